@@ -0,0 +1,361 @@
+use crate::auth::{get_credentials, Credentials};
+use crate::boards;
+use crate::project::get_project_or_default;
+use crate::web;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::json_patch_operation::Op;
+use azure_devops_rust_api::wit::models::{self, JsonPatchOperation, Link};
+use clap::Subcommand;
+use colored::Colorize;
+use serde_json::json;
+use std::collections::HashSet;
+use std::path::Path;
+use tokio::fs;
+
+#[derive(Subcommand, Clone)]
+pub enum AttachmentsSubCommands {
+    /// Download all attachments of a work item
+    Download {
+        /// ID of the work item to download attachments from
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        /// Output folder (defaults to current directory)
+        #[clap(long)]
+        dir: Option<String>,
+        /// Overwrite existing files
+        #[clap(long)]
+        overwrite: bool,
+    },
+}
+
+pub async fn handle_command(subcommand: &AttachmentsSubCommands) -> Result<()> {
+    match subcommand {
+        AttachmentsSubCommands::Download {
+            id,
+            project,
+            dir,
+            overwrite,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let output_dir = dir.as_deref().unwrap_or(".");
+            download_attachments(&project_name, id, output_dir, *overwrite).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Uploads the raw file content to the attachment store.
+///
+/// The generated attachments client serializes the body as a JSON string,
+/// which corrupts binary files, so the upload is sent directly.
+async fn upload_attachment(
+    creds: &Credentials,
+    project: &str,
+    file_name: &str,
+    content: Vec<u8>,
+) -> Result<models::AttachmentReference> {
+    let mut url = web::api_url(Some(project), &["wit", "attachments"])?;
+    url.query_pairs_mut()
+        .append_pair("fileName", file_name)
+        .append_pair("api-version", "7.1");
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .basic_auth("", Some(&creds.pat))
+        .header("Content-Type", "application/octet-stream")
+        .body(content)
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body = response.bytes().await?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+pub async fn attach_files(
+    project: &str,
+    id: &str,
+    files: &[String],
+    comment: Option<&str>,
+) -> Result<models::WorkItem> {
+    let id_int = id
+        .parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+
+    let creds = get_credentials()?;
+    let mut patch_operations = Vec::new();
+
+    for file in files {
+        let path = Path::new(file);
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| anyhow!("Invalid file path: {file}"))?;
+        let content = fs::read(path)
+            .await
+            .map_err(|e| anyhow!("Failed to read {file}: {e}"))?;
+
+        println!("Uploading {file_name} ({} bytes)...", content.len());
+        let attachment = upload_attachment(&creds, project, file_name, content).await?;
+        let url = attachment
+            .url
+            .ok_or_else(|| anyhow!("Upload of {file_name} did not return an attachment URL"))?;
+
+        patch_operations.push(JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some("/relations/-".to_owned()),
+            value: Some(json!({
+                "rel": "AttachedFile",
+                "url": url,
+                "attributes": { "comment": comment.unwrap_or_default() }
+            })),
+        });
+    }
+
+    let work_item = boards::create_client()?
+        .work_items_client()
+        .update(creds.organization, patch_operations, id_int, project)
+        .await?;
+
+    Ok(work_item)
+}
+
+async fn get_attachment_links(project: &str, id: &str) -> Result<Vec<Link>> {
+    let id_int = id
+        .parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+
+    let creds = get_credentials()?;
+    let work_item = boards::create_client()?
+        .work_items_client()
+        .get_work_item(creds.organization, id_int, project)
+        .expand("relations")
+        .await?;
+
+    Ok(work_item
+        .relations
+        .into_iter()
+        .map(|relation| relation.link)
+        .filter(|link| link.rel == "AttachedFile")
+        .collect())
+}
+
+/// Extracts the attachment GUID from an attachment URL
+fn attachment_id_from_url(url: &str) -> &str {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+}
+
+fn attachment_name(link: &Link) -> String {
+    link.attributes
+        .get("name")
+        .and_then(|v| v.as_str())
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| attachment_id_from_url(&link.url).to_string())
+}
+
+/// Reduces a server supplied attachment name to a plain file name so downloads
+/// can't escape the output directory. Returns `None` for names without one.
+fn safe_file_name(name: &str) -> Option<&str> {
+    let file_name = Path::new(name).file_name()?.to_str()?;
+    match file_name {
+        "" | "." | ".." => None,
+        _ => Some(file_name),
+    }
+}
+
+/// Returns `name`, or `name (n).ext` when an earlier attachment already used it
+fn unique_file_name(name: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{extension}")),
+        _ => (name, String::new()),
+    };
+    let mut n = 1;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{stem} ({n}){extension}");
+        n += 1;
+    }
+    candidate
+}
+
+pub async fn list_attachments(project: &str, id: &str) -> Result<()> {
+    let attachments = get_attachment_links(project, id).await?;
+
+    if attachments.is_empty() {
+        println!("No attachments found on work item {id}");
+        return Ok(());
+    }
+
+    println!(
+        "{:<40} {:<12} {:<22} {}",
+        "Name".bold(),
+        "Size".bold(),
+        "Created".bold(),
+        "Comment".bold()
+    );
+    println!("{}", "-".repeat(100));
+
+    for link in &attachments {
+        let size = link
+            .attributes
+            .get("resourceSize")
+            .and_then(serde_json::Value::as_i64)
+            .map(|size| format!("{size} B"))
+            .unwrap_or_else(|| "-".to_string());
+        let created = link
+            .attributes
+            .get("resourceCreatedDate")
+            .and_then(|v| v.as_str())
+            .unwrap_or("-");
+        let comment = link
+            .attributes
+            .get("comment")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+
+        println!(
+            "{:<40} {:<12} {:<22} {}",
+            attachment_name(link),
+            size,
+            created.chars().take(19).collect::<String>(),
+            comment
+        );
+    }
+
+    println!("\nTotal: {} attachments", attachments.len());
+    Ok(())
+}
+
+async fn download_attachments(
+    project: &str,
+    id: &str,
+    output_dir: &str,
+    overwrite: bool,
+) -> Result<()> {
+    let attachments = get_attachment_links(project, id).await?;
+
+    if attachments.is_empty() {
+        println!("No attachments found on work item {id}");
+        return Ok(());
+    }
+
+    let dir_path = Path::new(output_dir);
+    if !dir_path.exists() {
+        fs::create_dir_all(dir_path).await?;
+    }
+
+    let creds = get_credentials()?;
+    let client = boards::create_client()?;
+    let mut downloaded = 0;
+
+    let mut used_names = HashSet::new();
+
+    for link in &attachments {
+        let name = attachment_name(link);
+        let Some(file_name) = safe_file_name(&name) else {
+            eprintln!("⚠  Skipping attachment with invalid name '{name}'");
+            continue;
+        };
+        let file_name = unique_file_name(file_name, &mut used_names);
+        let file_path = dir_path.join(&file_name);
+
+        if file_path.exists() && !overwrite {
+            eprintln!(
+                "⚠  Skipping {}: file already exists. Use --overwrite to replace it.",
+                file_path.display()
+            );
+            continue;
+        }
+
+        let content = client
+            .attachments_client()
+            .get(
+                &creds.organization,
+                attachment_id_from_url(&link.url),
+                project,
+            )
+            .file_name(&name)
+            .download(true)
+            .send()
+            .await?
+            .into_raw_response()
+            .into_body();
+
+        fs::write(&file_path, content.as_ref()).await?;
+        println!("Downloaded: {} -> {}", name, file_path.display());
+        downloaded += 1;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "✅ Downloaded {downloaded} of {} attachments",
+            attachments.len()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_id_from_url() {
+        assert_eq!(
+            attachment_id_from_url(
+                "https://dev.azure.com/org/_apis/wit/attachments/6b7f4c3d-0000-4a1b-9c2d-1234567890ab"
+            ),
+            "6b7f4c3d-0000-4a1b-9c2d-1234567890ab"
+        );
+        assert_eq!(
+            attachment_id_from_url("https://dev.azure.com/org/_apis/wit/attachments/abc/"),
+            "abc"
+        );
+    }
+
+    #[test]
+    fn test_attachment_name_falls_back_to_id() {
+        let named = Link::new(
+            json!({ "name": "log.txt" }),
+            "AttachedFile".to_string(),
+            "https://dev.azure.com/org/_apis/wit/attachments/abc".to_string(),
+        );
+        assert_eq!(attachment_name(&named), "log.txt");
+
+        let unnamed = Link::new(
+            json!({}),
+            "AttachedFile".to_string(),
+            "https://dev.azure.com/org/_apis/wit/attachments/abc".to_string(),
+        );
+        assert_eq!(attachment_name(&unnamed), "abc");
+    }
+
+    #[test]
+    fn test_safe_file_name() {
+        assert_eq!(safe_file_name("log.txt"), Some("log.txt"));
+        assert_eq!(safe_file_name("../../.bashrc"), Some(".bashrc"));
+        assert_eq!(safe_file_name("/etc/passwd"), Some("passwd"));
+        assert_eq!(safe_file_name(".."), None);
+        assert_eq!(safe_file_name("."), None);
+        assert_eq!(safe_file_name(""), None);
+    }
+
+    #[test]
+    fn test_unique_file_name() {
+        let mut used = HashSet::new();
+        assert_eq!(unique_file_name("log.txt", &mut used), "log.txt");
+        assert_eq!(unique_file_name("log.txt", &mut used), "log (1).txt");
+        assert_eq!(unique_file_name("log.txt", &mut used), "log (2).txt");
+        assert_eq!(unique_file_name("README", &mut used), "README");
+        assert_eq!(unique_file_name("README", &mut used), "README (1)");
+    }
+}
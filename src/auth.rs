@@ -1,4 +1,5 @@
 use crate::config::get_config_dir;
+use crate::web;
 use anyhow::{anyhow, Result};
 use colored::Colorize;
 use dialoguer::{Input, Password};
@@ -13,6 +14,14 @@ pub struct Credentials {
     pub pat: String,
 }
 
+/// The identity the PAT belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentUser {
+    pub id: String,
+    pub display_name: String,
+    pub unique_name: String,
+}
+
 fn save_organization(organization: &str) -> Result<()> {
     let config_dir = get_config_dir()?;
     let config_file = config_dir.join("config.json");
@@ -157,3 +166,43 @@ pub fn get_credentials() -> Result<Credentials> {
 
     Ok(Credentials { organization, pat })
 }
+
+/// Looks up the user the PAT belongs to from the organization's connection data.
+///
+/// The generated clients have no endpoint for connection data, so it is requested directly.
+pub async fn get_current_user() -> Result<CurrentUser> {
+    let creds = get_credentials()?;
+    let mut url = web::api_url(None, &["connectionData"])?;
+    url.query_pairs_mut().append_pair("api-version", "7.1");
+
+    let response = reqwest::Client::new()
+        .get(url)
+        .basic_auth("", Some(&creds.pat))
+        .send()
+        .await?
+        .error_for_status()?;
+    let body: serde_json::Value = serde_json::from_slice(&response.bytes().await?)?;
+    current_user_from_connection_data(&body)
+}
+
+fn current_user_from_connection_data(body: &serde_json::Value) -> Result<CurrentUser> {
+    let user = &body["authenticatedUser"];
+    let id = user["id"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Unable to determine the current user"))?
+        .to_string();
+    let display_name = user["providerDisplayName"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    let unique_name = user["properties"]["Account"]["$value"]
+        .as_str()
+        .unwrap_or(&display_name)
+        .to_string();
+
+    Ok(CurrentUser {
+        id,
+        display_name,
+        unique_name,
+    })
+}
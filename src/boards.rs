@@ -1,13 +1,35 @@
+use crate::attachments::{self, AttachmentsSubCommands};
 use crate::auth::get_credentials;
+use crate::bulk::{self, FieldChange};
+use crate::burndown;
+use crate::capacity;
+use crate::classification::{self, AreaSubCommands, IterationSubCommands};
+use crate::export::{self, ExportFormat};
+use crate::history;
+use crate::import;
+use crate::kanban::{self, BoardSubCommands};
 use crate::project::get_project_or_default;
+use crate::query::{self, QuerySource};
+use crate::recycle_bin::{self, RecycleBinSubCommands};
+use crate::relations::{self, Artifact, ArtifactRef, Relation};
+use crate::repos;
+use crate::roadmap::{self, RoadmapFormat};
+use crate::sprint;
+use crate::states;
+use crate::tags::{self, TagSubCommands};
+use crate::templates::{self, TemplateSubCommands};
+use crate::watch;
+use crate::web;
 use anyhow::{anyhow, Result};
+use azure_devops_rust_api::git::models::GitRepository;
 use azure_devops_rust_api::wit::models::json_patch_operation::Op;
 use azure_devops_rust_api::wit::models::JsonPatchOperation;
 use azure_devops_rust_api::wit::{self, models, ClientBuilder};
-use clap::Subcommand;
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use clap::{Subcommand, ValueEnum};
 use colored::Colorize;
 use serde_json::json;
-use std::process::Command;
+use std::collections::HashMap;
 
 #[derive(Subcommand, Clone)]
 pub enum BoardsSubCommands {
@@ -16,6 +38,114 @@ pub enum BoardsSubCommands {
         #[clap(subcommand)]
         subcommand: WorkItemSubCommands,
     },
+    /// Run a WIQL query or a saved query
+    Query {
+        /// WIQL query to run (e.g., "SELECT [System.Id] FROM WorkItems WHERE [System.State] = 'Active'")
+        #[clap(long, required_unless_present_any = ["file", "saved"], conflicts_with_all = ["file", "saved"])]
+        wiql: Option<String>,
+        /// Read the WIQL query from a file
+        #[clap(long, conflicts_with = "saved")]
+        file: Option<String>,
+        /// Path of a saved query to run (e.g., 'Shared Queries/Team/Open Bugs')
+        #[clap(long)]
+        saved: Option<String>,
+        /// Comma-separated list of fields to display (defaults to the query's columns)
+        #[clap(long, value_delimiter = ',')]
+        fields: Vec<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        /// Maximum number of work items to return (default: 50)
+        #[clap(long, default_value = "50")]
+        limit: i32,
+    },
+    /// Show and move cards on a team's Kanban board
+    Board {
+        #[clap(subcommand)]
+        subcommand: BoardSubCommands,
+    },
+    /// Show a sprint with its dates, work items by state and capacity per person
+    Sprint {
+        /// Team name (defaults to the project's default team)
+        #[clap(long)]
+        team: Option<String>,
+        /// Iteration to show: 'current', 'next' or an iteration path
+        #[clap(long, default_value = "current")]
+        iteration: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Show a sprint's capacity per person and activity, days off and remaining work of Tasks
+    Capacity {
+        /// Team name (defaults to the project's default team)
+        #[clap(long)]
+        team: Option<String>,
+        /// Iteration to show: 'current', 'next' or an iteration path
+        #[clap(long, default_value = "current")]
+        iteration: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Draw a burndown chart of a sprint's remaining work from the Task history
+    Burndown {
+        /// Team name (defaults to the project's default team)
+        #[clap(long)]
+        team: Option<String>,
+        /// Iteration to show: 'current', 'next' or an iteration path
+        #[clap(long, default_value = "current")]
+        iteration: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Show the Epics and Features of an area on a timeline with their progress
+    Roadmap {
+        /// Area path to show, including the Epics and Features of its sub-areas
+        #[clap(long)]
+        area: String,
+        /// Only show items planned in this quarter, e.g. 2026Q4
+        #[clap(long)]
+        quarter: Option<String>,
+        /// Output format: a Gantt-style chart or a Markdown table
+        #[clap(long, value_enum, default_value = "chart")]
+        format: RoadmapFormat,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Poll the followed work items (or a WIQL query) and print a line for every changed field
+    Watch {
+        /// WIQL query selecting the work items to watch instead of the followed ones
+        #[clap(long)]
+        wiql: Option<String>,
+        /// Seconds between polls
+        #[clap(long, default_value_t = 60)]
+        interval: u64,
+        /// Command to run on every change, e.g. a desktop notification; receives
+        /// AZDOCLI_WORK_ITEM_ID, AZDOCLI_TITLE and AZDOCLI_MESSAGE in its environment
+        #[clap(long)]
+        notify: Option<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// List, rename and delete work item tags
+    Tags {
+        #[clap(subcommand)]
+        subcommand: TagSubCommands,
+    },
+    /// Manage the area paths of a project
+    Areas {
+        #[clap(subcommand)]
+        subcommand: AreaSubCommands,
+    },
+    /// Manage the iteration paths of a project
+    Iterations {
+        #[clap(subcommand)]
+        subcommand: IterationSubCommands,
+    },
 }
 
 #[derive(Subcommand, Clone, Debug)]
@@ -33,16 +163,250 @@ pub enum WorkItemType {
     Epic,
 }
 
+impl WorkItemType {
+    /// The work item type name used by Azure DevOps
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkItemType::Bug => "Bug",
+            WorkItemType::Task => "Task",
+            WorkItemType::UserStory => "User Story",
+            WorkItemType::Feature => "Feature",
+            WorkItemType::Epic => "Epic",
+        }
+    }
+}
+
+/// Sort orders for `work-item list`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum WorkItemSort {
+    /// Newest first
+    Created,
+    /// Most recently changed first
+    Changed,
+    /// Highest priority first
+    Priority,
+    /// Ascending work item ID
+    Id,
+    /// Alphabetically by title
+    Title,
+    /// Alphabetically by state
+    State,
+}
+
+impl WorkItemSort {
+    fn order_by(self) -> &'static str {
+        match self {
+            WorkItemSort::Created => "[System.CreatedDate] DESC",
+            WorkItemSort::Changed => "[System.ChangedDate] DESC",
+            WorkItemSort::Priority => "[Microsoft.VSTS.Common.Priority] ASC",
+            WorkItemSort::Id => "[System.Id] ASC",
+            WorkItemSort::Title => "[System.Title] ASC",
+            WorkItemSort::State => "[System.State] ASC",
+        }
+    }
+}
+
+/// Lower bound for the `--changed-since` filter and the stand-up's `--since`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangedSince {
+    Date(NaiveDate),
+    DaysAgo(u32),
+}
+
+impl ChangedSince {
+    /// Parses 'today', 'yesterday', a number of days such as '7d', or a date (YYYY-MM-DD).
+    ///
+    /// 'yesterday' is the previous working day, so on a Monday (or over the weekend) it
+    /// goes back to Friday. A number of days counts calendar days, weekends included.
+    pub fn parse(value: &str, today: NaiveDate) -> Result<Self, String> {
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "today" => return Ok(ChangedSince::DaysAgo(0)),
+            "yesterday" => {
+                let days_back = match today.weekday() {
+                    Weekday::Mon => 3,
+                    Weekday::Sun => 2,
+                    _ => 1,
+                };
+                return Ok(ChangedSince::DaysAgo(days_back));
+            }
+            _ => {}
+        }
+
+        if let Some(days) = value.strip_suffix('d') {
+            return days
+                .parse::<u32>()
+                .map(ChangedSince::DaysAgo)
+                .map_err(|_| format!("Invalid number of days: '{value}'"));
+        }
+
+        NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+            .map(ChangedSince::Date)
+            .map_err(|_| {
+                format!(
+                    "Invalid date '{value}', expected YYYY-MM-DD, 'today', 'yesterday' or e.g. '7d'"
+                )
+            })
+    }
+
+    /// The first day included, counted from `today`
+    pub fn date(&self, today: NaiveDate) -> NaiveDate {
+        match self {
+            ChangedSince::Date(date) => *date,
+            ChangedSince::DaysAgo(days) => today - Duration::days(i64::from(*days)),
+        }
+    }
+}
+
+/// Parses a `--changed-since` or `--since` argument relative to the local date
+pub fn parse_changed_since(value: &str) -> Result<ChangedSince, String> {
+    ChangedSince::parse(value, Local::now().date_naive())
+}
+
+/// Filters for `work-item list`, composed into a WIQL query by `build_wiql_query`
+#[derive(Default)]
+pub struct WorkItemFilters<'a> {
+    pub state: Option<&'a str>,
+    pub work_item_type: Option<&'a str>,
+    /// 'me', 'unassigned', 'any' or a user; `None` behaves like 'any'
+    pub assigned_to: Option<&'a str>,
+    pub area: Option<&'a str>,
+    pub iteration: Option<&'a str>,
+    pub tags: &'a [String],
+    pub created_by: Option<&'a str>,
+    pub changed_since: Option<&'a ChangedSince>,
+    pub priority: Option<i32>,
+    pub sort: Option<WorkItemSort>,
+}
+
+impl WorkItemFilters<'_> {
+    fn is_assigned_to_me(&self) -> bool {
+        self.assigned_to
+            .map(|a| a.eq_ignore_ascii_case("me"))
+            .unwrap_or(false)
+    }
+}
+
 #[derive(Subcommand, Clone)]
 pub enum WorkItemSubCommands {
     /// Create a new work item
     Create {
-        /// Work item type
+        /// Work item type (optional when --template is given)
         #[clap(subcommand)]
-        work_item_type: WorkItemType,
+        work_item_type: Option<WorkItemType>,
         /// Work item title
         #[clap(short, long)]
         title: String,
+        /// Name of a saved template to take the type and fields from
+        #[clap(long)]
+        template: Option<String>,
+        /// Value for a template placeholder, e.g. 'service=payments'
+        #[clap(long = "var", requires = "template")]
+        vars: Vec<String>,
+        /// Also create this branch from the default branch of --repo and link it
+        #[clap(long, requires = "repo")]
+        branch: Option<String>,
+        /// Repository to create the branch in
+        #[clap(long, requires = "branch")]
+        repo: Option<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Manage work item templates
+    Template {
+        #[clap(subcommand)]
+        subcommand: TemplateSubCommands,
+    },
+    /// Import work items from a CSV file or a Markdown plan
+    Import {
+        /// CSV file, or Markdown file whose nested bullets become Epics, Features, Stories and Tasks
+        #[clap(short, long)]
+        file: String,
+        /// Map a CSV column to a field, e.g. 'Estimate=Microsoft.VSTS.Scheduling.StoryPoints'
+        #[clap(long = "map")]
+        mappings: Vec<String>,
+        /// Work item type for CSV rows without a 'Type' column
+        #[clap(long = "type", default_value = "User Story")]
+        default_type: String,
+        /// Work item types for each level of a Markdown plan, e.g. 'Epic,Feature,Issue,Task'
+        /// (default: the backlog types of the project's process)
+        #[clap(long, value_delimiter = ',')]
+        types: Vec<String>,
+        /// ID of an existing work item to add the top-level items under
+        #[clap(long)]
+        parent: Option<String>,
+        /// Preview the JSON patches without creating any work items
+        #[clap(long)]
+        dry_run: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Export the results of a WIQL query to a CSV, JSON or Markdown file
+    Export {
+        /// WIQL query selecting the work items to export
+        #[clap(short, long)]
+        wiql: String,
+        /// Output format
+        #[clap(long, value_enum, default_value = "csv")]
+        format: ExportFormat,
+        /// Fields to export (comma-separated); defaults to the query's columns
+        #[clap(long, value_delimiter = ',')]
+        fields: Vec<String>,
+        /// File to write (defaults to work-items.<format>)
+        #[clap(short, long)]
+        output: Option<String>,
+        /// Include the parent ID of each work item
+        #[clap(long)]
+        with_relations: bool,
+        /// Include the time of each state change
+        #[clap(long)]
+        with_history: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Update every work item matched by a WIQL query
+    BulkUpdate {
+        /// WIQL query selecting the work items to update
+        #[clap(short, long)]
+        wiql: String,
+        /// Change to apply: 'Field=Value', 'System.Tags+=tag' or 'System.Tags-=tag'
+        #[clap(long = "set", required = true, value_parser = bulk::parse_field_change)]
+        changes: Vec<FieldChange>,
+        /// Preview the JSON patches without updating any work items
+        #[clap(long)]
+        dry_run: bool,
+        /// Skip confirmation prompt and proceed directly
+        #[clap(short = 'y', long)]
+        yes: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// List, restore or permanently destroy deleted work items
+    RecycleBin {
+        #[clap(subcommand)]
+        subcommand: RecycleBinSubCommands,
+    },
+    /// List the revisions of a work item with the fields changed in each
+    History {
+        /// ID of the work item
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Compare the fields of two revisions of a work item
+    Diff {
+        /// ID of the work item
+        #[clap(short, long)]
+        id: String,
+        /// The two revision numbers to compare, e.g. --rev 3 --rev 7
+        #[clap(long = "rev", num_args = 1, required = true)]
+        revs: Vec<i32>,
         /// Team project name (optional if default project is set)
         #[clap(short, long)]
         project: Option<String>,
@@ -55,11 +419,11 @@ pub enum WorkItemSubCommands {
         /// Team project name (optional if default project is set)
         #[clap(short, long)]
         project: Option<String>,
-        /// Perform soft delete by changing state to removed (default is permanent delete)
+        /// Perform soft delete by changing to the type's Removed state (default moves it to the recycle bin)
         #[clap(long)]
         soft_delete: bool,
     },
-    /// List work items assigned to me
+    /// List work items (assigned to me by default)
     List {
         /// Team project name (optional if default project is set)
         #[clap(short, long)]
@@ -70,6 +434,30 @@ pub enum WorkItemSubCommands {
         /// Filter by work item type (e.g., 'Bug', 'Task', 'User Story')
         #[clap(long)]
         work_item_type: Option<String>,
+        /// Filter by assignee: 'me', 'unassigned', 'any' or a user name/email
+        #[clap(long, default_value = "me")]
+        assigned_to: String,
+        /// Filter by area path (includes child areas)
+        #[clap(long)]
+        area: Option<String>,
+        /// Filter by iteration path (includes child iterations), or '@current' for the current sprint
+        #[clap(long)]
+        iteration: Option<String>,
+        /// Filter by tag (can be repeated; all tags must match)
+        #[clap(long)]
+        tag: Vec<String>,
+        /// Filter by creator: 'me' or a user name/email
+        #[clap(long)]
+        created_by: Option<String>,
+        /// Only include items changed since a date (YYYY-MM-DD), 'today', 'yesterday' (previous working day) or a number of days (e.g., '7d')
+        #[clap(long, value_parser = parse_changed_since)]
+        changed_since: Option<ChangedSince>,
+        /// Filter by priority (e.g., 1, 2, 3, 4)
+        #[clap(long)]
+        priority: Option<i32>,
+        /// Sort order of the results
+        #[clap(long, value_enum, default_value_t = WorkItemSort::Created)]
+        sort: WorkItemSort,
         /// Maximum number of work items to return (default: 50)
         #[clap(long, default_value = "50")]
         limit: i32,
@@ -85,6 +473,10 @@ pub enum WorkItemSubCommands {
         /// Open in web browser
         #[clap(long)]
         web: bool,
+        /// Fields to show instead of the summary: 'all', or a comma-separated list
+        /// of reference names (e.g. System.AreaPath,Microsoft.VSTS.Common.Priority)
+        #[clap(long)]
+        fields: Option<String>,
     },
     /// Update a work item
     Update {
@@ -106,10 +498,79 @@ pub enum WorkItemSubCommands {
         /// New priority for the work item (e.g., 1, 2, 3, 4)
         #[clap(long)]
         priority: Option<i32>,
+        /// Add a tag with '+tag' or remove one with '-tag', can be repeated
+        #[clap(long = "tag", allow_hyphen_values = true, value_parser = bulk::parse_tag_edit)]
+        tags: Vec<FieldChange>,
+        /// Only update if the work item is still at this revision
+        #[clap(long)]
+        expect_rev: Option<i32>,
+    },
+    /// Upload files and attach them to a work item
+    Attach {
+        /// ID of the work item to attach files to
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        /// Comment to add to each attachment
+        #[clap(long)]
+        comment: Option<String>,
+        /// Files to upload
+        #[clap(required = true)]
+        files: Vec<String>,
+    },
+    /// Link a work item to pull requests, commits or branches
+    Link {
+        /// ID of the work item to link
+        #[clap(short, long)]
+        id: String,
+        /// Pull request to link, as <repo>!<pr-id> (can be repeated)
+        #[clap(long, value_parser = relations::parse_pull_request_ref, required_unless_present_any = ["commit", "branch"])]
+        pr: Vec<ArtifactRef>,
+        /// Commit to link, as <repo>@<sha> (can be repeated)
+        #[clap(long, value_parser = relations::parse_commit_ref)]
+        commit: Vec<ArtifactRef>,
+        /// Branch to link, as <repo>:<branch> (can be repeated)
+        #[clap(long, value_parser = relations::parse_branch_ref)]
+        branch: Vec<ArtifactRef>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Follow a work item to watch it with 'boards watch' (kept locally, no emails are sent)
+    Follow {
+        /// ID of the work item to follow
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Stop following a work item
+    Unfollow {
+        /// ID of the work item to stop following
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// List attachments of a work item
+    #[clap(subcommand_negates_reqs = true)]
+    Attachments {
+        /// ID of the work item to list attachments for
+        #[clap(short, long, required = true)]
+        id: Option<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        #[clap(subcommand)]
+        subcommand: Option<AttachmentsSubCommands>,
     },
 }
 
-fn create_client() -> Result<wit::Client> {
+pub fn create_client() -> Result<wit::Client> {
     match get_credentials() {
         Ok(creds) => {
             let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
@@ -120,7 +581,13 @@ fn create_client() -> Result<wit::Client> {
     }
 }
 
-async fn get_work_item(project: &str, id: &str) -> Result<models::WorkItem> {
+/// Gets a work item. `expand` asks for extra data, e.g. "relations" for its links to
+/// other work items and artifacts.
+pub async fn get_work_item(
+    project: &str,
+    id: &str,
+    expand: Option<&str>,
+) -> Result<models::WorkItem> {
     let id_int = id
         .parse::<i32>()
         .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
@@ -128,12 +595,15 @@ async fn get_work_item(project: &str, id: &str) -> Result<models::WorkItem> {
     match get_credentials() {
         Ok(creds) => {
             let client = create_client()?;
-            let work_item = client
-                .work_items_client()
-                .get_work_item(creds.organization, id_int, project)
-                .await?;
+            let mut request =
+                client
+                    .work_items_client()
+                    .get_work_item(creds.organization, id_int, project);
+            if let Some(expand) = expand {
+                request = request.expand(expand);
+            }
 
-            Ok(work_item)
+            Ok(request.await?)
         }
         Err(e) => {
             eprintln!("Unable to retrieve work item");
@@ -142,32 +612,193 @@ async fn get_work_item(project: &str, id: &str) -> Result<models::WorkItem> {
     }
 }
 
-async fn create_work_item(
+/// Resolves the given pull requests, commits and branches and links them to a work item
+async fn link_work_item(project: &str, id: &str, artifacts: &[&ArtifactRef]) -> Result<()> {
+    let id_int = id
+        .parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+
+    let mut resolved = Vec::with_capacity(artifacts.len());
+    for artifact in artifacts {
+        resolved.push(relations::resolve_artifact(project, artifact).await?);
+    }
+    relations::link_artifacts(project, id_int, &resolved).await?;
+
+    for artifact in artifacts {
+        let description = match artifact {
+            ArtifactRef::PullRequest { repo, id } => format!("pull request !{id} ({repo})"),
+            ArtifactRef::Commit { repo, sha } => format!("commit {sha} ({repo})"),
+            ArtifactRef::Branch { repo, name } => format!("branch '{name}' ({repo})"),
+        };
+        println!(
+            "{}",
+            format!("✅ Linked {description} to work item {id}").green()
+        );
+    }
+    Ok(())
+}
+
+/// A branch to create for a new work item, validated before the work item exists
+struct NewBranch {
+    repo: GitRepository,
+    project_id: String,
+    name: String,
+    /// Head of the default branch the branch starts from
+    head: String,
+}
+
+/// Resolves the repository and the head of its default branch, and checks that the
+/// branch doesn't exist yet, so a failure doesn't leave an orphaned work item behind
+async fn prepare_linked_branch(project: &str, repo: &str, branch: &str) -> Result<NewBranch> {
+    let repo = repos::get_repo(project, repo).await?;
+    let project_id = repo
+        .project
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Project ID of repository '{}' is missing", repo.name))?;
+    let head = repos::new_branch_start(project, &repo, branch).await?;
+    Ok(NewBranch {
+        repo,
+        project_id,
+        // The link and the checkout hint use the short branch name
+        name: branch.trim_start_matches("refs/heads/").to_string(),
+        head,
+    })
+}
+
+/// Creates a prepared branch and links it to a work item
+async fn create_linked_branch(project: &str, id: i32, branch: NewBranch) -> Result<()> {
+    let NewBranch {
+        repo,
+        project_id,
+        name,
+        head,
+    } = branch;
+    repos::create_branch(project, &repo, &name, head).await?;
+    relations::link_artifacts(
+        project,
+        id,
+        &[Artifact::Branch {
+            project_id,
+            repository_id: repo.id.clone(),
+            name: name.clone(),
+        }],
+    )
+    .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Created branch '{name}' in '{}' and linked it to work item {id}",
+            repo.name
+        )
+        .green()
+    );
+    println!("💡 Check it out with: git fetch && git checkout {name}");
+    Ok(())
+}
+
+/// The type, title and fields of a work item about to be created
+struct NewWorkItem {
+    work_item_type: String,
+    title: String,
+    fields: Vec<(String, serde_json::Value)>,
+}
+
+/// Works out the type, title and fields of a new work item, applying a template if one is given
+fn resolve_new_work_item(
+    work_item_type: Option<&WorkItemType>,
+    title: &str,
+    template: Option<&str>,
+    vars: &[String],
+) -> Result<NewWorkItem> {
+    match (template, work_item_type) {
+        (Some(name), work_item_type) => {
+            let template = templates::load_template(name)?;
+            let vars = templates::parse_vars(vars)?;
+            Ok(NewWorkItem {
+                work_item_type: work_item_type
+                    .map(|t| t.name().to_string())
+                    .unwrap_or(template.work_item_type.clone()),
+                title: templates::render(title, &vars)?,
+                fields: templates::render_fields(&template, &vars)?,
+            })
+        }
+        (None, Some(work_item_type)) => Ok(NewWorkItem {
+            work_item_type: work_item_type.name().to_string(),
+            title: title.to_string(),
+            fields: Vec::new(),
+        }),
+        (None, None) => Err(anyhow!(
+            "Specify a work item type (bug, task, user-story, feature, epic) or --template"
+        )),
+    }
+}
+
+/// Builds the patch that creates a work item with a title, extra fields and an optional parent.
+///
+/// `work_items_url` is the `_apis/wit/workItems` URL of the server the parent link points to.
+pub fn create_patch_operations(
+    work_items_url: &str,
+    title: &str,
+    fields: &[(String, serde_json::Value)],
+    parent: Option<&str>,
+) -> Vec<JsonPatchOperation> {
+    let mut patch_operations = vec![JsonPatchOperation {
+        from: None,
+        op: Some(Op::Add),
+        path: Some("/fields/System.Title".to_owned()),
+        value: Some(json!(title)),
+    }];
+
+    for (field, value) in fields {
+        patch_operations.push(JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some(format!("/fields/{field}")),
+            value: Some(value.clone()),
+        });
+    }
+
+    if let Some(parent) = parent {
+        patch_operations.push(JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some("/relations/-".to_owned()),
+            value: Some(json!({
+                "rel": "System.LinkTypes.Hierarchy-Reverse",
+                "url": format!("{work_items_url}/{parent}")
+            })),
+        });
+    }
+
+    patch_operations
+}
+
+pub async fn create_work_item(
     project: &str,
-    work_item_type: &WorkItemType,
+    work_item_type: &str,
     title: &str,
+    fields: &[(String, serde_json::Value)],
+    parent: Option<i32>,
 ) -> Result<models::WorkItem> {
     match get_credentials() {
         Ok(creds) => {
             let client = create_client()?;
+            let parent = parent.map(|id| id.to_string());
+            let work_items_url = web::api_url(None, &["wit", "workItems"])?;
             let work_item = client
                 .work_items_client()
                 .create(
                     creds.organization.clone(),
-                    vec![JsonPatchOperation {
-                        from: None,
-                        op: Some(Op::Add),
-                        path: Some("/fields/System.Title".to_owned()),
-                        value: Some(json!(title)),
-                    }],
+                    create_patch_operations(
+                        work_items_url.as_str(),
+                        title,
+                        fields,
+                        parent.as_deref(),
+                    ),
                     project.to_string(),
-                    match work_item_type {
-                        WorkItemType::Bug => "Bug",
-                        WorkItemType::Task => "Task",
-                        WorkItemType::UserStory => "User Story",
-                        WorkItemType::Feature => "Feature",
-                        WorkItemType::Epic => "Epic",
-                    },
+                    work_item_type,
                 )
                 .await?;
 
@@ -180,23 +811,68 @@ async fn create_work_item(
     }
 }
 
+/// Checks a requested state against the allowed transitions of the work item's type
+async fn validate_state(project: &str, id: &str, state: &str) -> Result<String> {
+    let work_item = get_work_item(project, id, None).await?;
+    let field = |name: &str| {
+        work_item
+            .fields
+            .get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let type_states = states::get_type_states(project, &field("System.WorkItemType")).await?;
+    type_states.validate_transition(&field("System.State"), state)
+}
+
+/// The fields to change with `work-item update`
+#[derive(Default)]
+struct WorkItemChanges<'a> {
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    state: Option<&'a str>,
+    priority: Option<i32>,
+    tags: &'a [FieldChange],
+}
+
 async fn update_work_item(
     project: &str,
     id: &str,
-    title: Option<&str>,
-    description: Option<&str>,
-    state: Option<&str>,
-    priority: Option<i32>,
+    changes: &WorkItemChanges<'_>,
+    expect_rev: Option<i32>,
 ) -> Result<models::WorkItem> {
     let id_int = id
         .parse::<i32>()
         .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+    let WorkItemChanges {
+        title,
+        description,
+        state,
+        priority,
+        tags,
+    } = *changes;
+
+    let state = match state {
+        Some(state) => Some(validate_state(project, id, state).await?),
+        None => None,
+    };
 
     match get_credentials() {
         Ok(creds) => {
             let client = create_client()?;
             let mut patch_operations = Vec::new();
 
+            // The update is rejected if someone else changed the work item in the meantime
+            if let Some(rev) = expect_rev {
+                patch_operations.push(JsonPatchOperation {
+                    from: None,
+                    op: Some(Op::Test),
+                    path: Some("/rev".to_owned()),
+                    value: Some(json!(rev)),
+                });
+            }
+
             if let Some(title) = title {
                 patch_operations.push(JsonPatchOperation {
                     from: None,
@@ -215,7 +891,7 @@ async fn update_work_item(
                 });
             }
 
-            if let Some(state) = state {
+            if let Some(state) = &state {
                 patch_operations.push(JsonPatchOperation {
                     from: None,
                     op: Some(Op::Add),
@@ -233,7 +909,13 @@ async fn update_work_item(
                 });
             }
 
-            let work_item = client
+            // Tags are a single semicolon separated field, so the current value is edited
+            if !tags.is_empty() {
+                let work_item = get_work_item(project, id, None).await?;
+                patch_operations.extend(bulk::patch_operations(&work_item, tags));
+            }
+
+            let result = client
                 .work_items_client()
                 .update(
                     creds.organization,
@@ -241,8 +923,18 @@ async fn update_work_item(
                     id_int,
                     project.to_string(),
                 )
-                .await?;
-            Ok(work_item)
+                .await;
+
+            match (result, expect_rev) {
+                (Ok(work_item), _) => Ok(work_item),
+                (Err(e), Some(rev)) => {
+                    match history::revision_conflict(project, id_int, rev).await? {
+                        Some(conflict) => Err(conflict),
+                        None => Err(e.into()),
+                    }
+                }
+                (Err(e), None) => Err(e.into()),
+            }
         }
         Err(e) => {
             eprintln!("Unable to update work item");
@@ -258,21 +950,21 @@ async fn delete_work_item(project: &str, id: &str, soft_delete: bool) -> Result<
     match get_credentials() {
         Ok(creds) => {
             if soft_delete {
-                let work_item = get_work_item(project, id).await?;
+                let work_item = get_work_item(project, id, None).await?;
                 let work_item_type = work_item
                     .fields
                     .get("System.WorkItemType")
-                    .and_then(|v| v.as_str());
-                let state = work_item_type
-                    .map(|wt| {
-                        if wt == "Task" || wt == "User Story" || wt == "Feature" || wt == "Epic" {
-                            "Removed"
-                        } else {
-                            "Closed"
-                        }
-                    })
-                    .unwrap_or("Closed");
-                update_work_item(project, id, None, None, Some(state), None).await?;
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default();
+                let type_states = states::get_type_states(project, work_item_type).await?;
+                let state = type_states.removed_state().ok_or_else(|| {
+                    anyhow!("{} has no Removed or Completed state", work_item_type)
+                })?;
+                let changes = WorkItemChanges {
+                    state: Some(state),
+                    ..Default::default()
+                };
+                update_work_item(project, id, &changes, None).await?;
             } else {
                 create_client()?
                     .work_items_client()
@@ -292,31 +984,116 @@ async fn delete_work_item(project: &str, id: &str, soft_delete: bool) -> Result<
     }
 }
 
-fn open_work_item_in_browser(organization: &str, id: &str) -> Result<()> {
-    let url = format!("https://dev.azure.com/{organization}//_workitems/edit/{id}");
+/// Maximum number of work items the batch endpoint accepts per request
+const WORK_ITEMS_BATCH_SIZE: usize = 200;
 
-    #[cfg(target_os = "windows")]
-    {
-        Command::new("cmd")
-            .args(["/C", &format!("start {}", url)])
-            .spawn()?;
-    }
+/// Gets detailed work item information using the batch endpoint, in chunks of 200 IDs.
+///
+/// When `fields` is empty all fields are returned. Results keep the order of `ids`.
+pub async fn get_work_items_details(
+    client: &wit::Client,
+    organization: &str,
+    project: &str,
+    ids: &[i32],
+    fields: &[String],
+) -> Vec<models::WorkItem> {
+    let mut detailed_work_items = Vec::with_capacity(ids.len());
+
+    for chunk in ids.chunks(WORK_ITEMS_BATCH_SIZE) {
+        let request = models::WorkItemBatchGetRequest {
+            fields: fields.to_vec(),
+            ids: chunk.to_vec(),
+            ..Default::default()
+        };
 
-    #[cfg(target_os = "macos")]
-    {
-        Command::new("open").arg(&url).spawn()?;
+        match client
+            .work_items_client()
+            .get_work_items_batch(organization, request, project)
+            .await
+        {
+            Ok(list) => detailed_work_items.extend(list.value),
+            Err(e) => {
+                // A single inaccessible item fails the whole batch, so fall back to
+                // fetching this chunk one by one to report exactly which items failed
+                eprintln!("⚠  Batch request failed ({e}), retrying items individually");
+                for id in chunk {
+                    let mut request =
+                        client
+                            .work_items_client()
+                            .get_work_item(organization, *id, project);
+                    if !fields.is_empty() {
+                        request = request.fields(fields.join(","));
+                    }
+                    match request.await {
+                        Ok(detailed_item) => detailed_work_items.push(detailed_item),
+                        Err(e) => {
+                            eprintln!("❌ Failed to get details for work item {id}: {e}")
+                        }
+                    }
+                }
+            }
+        }
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        Command::new("xdg-open").arg(&url).spawn()?;
+    let order: HashMap<i32, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+    detailed_work_items.sort_by_key(|wi| order.get(&wi.id).copied());
+    detailed_work_items
+}
+
+fn open_work_item_in_browser(project: &str, id: &str) -> Result<()> {
+    let url = web::web_url(&[project, "_workitems", "edit", id])?;
+    web::open_in_browser(url.as_str())
+}
+
+/// Picks the fields named in `--fields` ('all' or a comma-separated list) with their
+/// displayed values. Names match the reference name or its last part, ignoring case.
+pub fn select_fields(fields: &serde_json::Value, selection: &str) -> Vec<(String, String)> {
+    let Some(fields) = fields.as_object() else {
+        return Vec::new();
+    };
+    if selection.trim().eq_ignore_ascii_case("all") {
+        let mut all: Vec<(String, String)> = fields
+            .iter()
+            .map(|(name, value)| (name.clone(), query::format_field_value(value)))
+            .collect();
+        all.sort();
+        return all;
     }
 
-    println!("Opening work item in browser: {url}");
-    Ok(())
+    selection
+        .split(',')
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let found = fields.iter().find(|(field, _)| {
+                field.eq_ignore_ascii_case(name)
+                    || field
+                        .rsplit('.')
+                        .next()
+                        .is_some_and(|last| last.eq_ignore_ascii_case(name))
+            });
+            match found {
+                Some((field, value)) => (field.clone(), query::format_field_value(value)),
+                None => (name.to_string(), "(not set)".to_string()),
+            }
+        })
+        .collect()
 }
 
-fn display_work_item(work_item: &models::WorkItem) {
+fn display_relations(relations: &[Relation]) {
+    if relations.is_empty() {
+        return;
+    }
+    println!("\n🔗 Relations:");
+    for relation in relations {
+        println!(
+            "  {:<14} {:<12} {}",
+            relation.kind, relation.target, relation.title
+        );
+    }
+}
+
+fn display_work_item(work_item: &models::WorkItem, relations: &[Relation], fields: Option<&str>) {
     println!("📋 Work Item Details");
     println!("=====================");
 
@@ -326,52 +1103,78 @@ fn display_work_item(work_item: &models::WorkItem) {
         println!("📚 Revision: {rev}");
     }
 
-    if let Some(fields) = work_item.fields.as_object() {
-        if let Some(title) = fields.get("System.Title").and_then(|v| v.as_str()) {
-            println!("📝 Title: {title}");
-        }
-
-        if let Some(state) = fields.get("System.State").and_then(|v| v.as_str()) {
-            println!("🔄 State: {state}");
-        }
-
-        if let Some(work_item_type) = fields.get("System.WorkItemType").and_then(|v| v.as_str()) {
-            println!("📌 Type: {work_item_type}");
+    if let Some(selection) = fields {
+        let selected = select_fields(&work_item.fields, selection);
+        let width = selected
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        println!();
+        for (name, value) in selected {
+            println!("{name:<width$}  {value}");
         }
+        display_relations(relations);
+        return;
+    }
 
-        if let Some(created_by) = fields.get("System.CreatedBy").and_then(|v| v.as_str()) {
-            println!("👤 Created By: {created_by}");
-        }
+    if let Some(fields) = work_item.fields.as_object() {
+        // Identity fields are objects, so every value goes through format_field_value
+        let field = |name: &str| {
+            fields
+                .get(name)
+                .map(query::format_field_value)
+                .filter(|v| !v.is_empty())
+        };
 
-        if let Some(created_date) = fields.get("System.CreatedDate").and_then(|v| v.as_str()) {
-            println!("📅 Created Date: {created_date}");
+        for (label, name) in [
+            ("📝 Title", "System.Title"),
+            ("🔄 State", "System.State"),
+            ("📌 Type", "System.WorkItemType"),
+            ("🙋 Assigned To", "System.AssignedTo"),
+            ("🗂️  Area", "System.AreaPath"),
+            ("🏃 Iteration", "System.IterationPath"),
+            ("🏷️  Tags", "System.Tags"),
+            ("👤 Created By", "System.CreatedBy"),
+            ("📅 Created Date", "System.CreatedDate"),
+            ("🔄 Changed By", "System.ChangedBy"),
+            ("📅 Changed Date", "System.ChangedDate"),
+            ("🔝 Priority", "Microsoft.VSTS.Common.Priority"),
+        ] {
+            if let Some(value) = field(name) {
+                println!("{label}: {value}");
+            }
         }
 
-        if let Some(changed_by) = fields.get("System.ChangedBy").and_then(|v| v.as_str()) {
-            println!("🔄 Changed By: {changed_by}");
+        if let Some(desc) = field("System.Description") {
+            println!("\n📄 Description:");
+            println!("{desc}");
         }
+    }
 
-        if let Some(changed_date) = fields.get("System.ChangedDate").and_then(|v| v.as_str()) {
-            println!("📅 Changed Date: {changed_date}");
-        }
+    display_relations(relations);
+    println!();
+    println!("💡 Use --fields all to show every field");
+}
 
-        if let Some(priority) = fields
-            .get("Microsoft.VSTS.Common.Priority")
-            .and_then(serde_json::Value::as_i64)
-        {
-            println!("🔝 Priority: {priority}");
-        }
+/// Fields needed to render the work items table
+const LIST_FIELDS: [&str; 3] = ["System.WorkItemType", "System.State", "System.Title"];
 
-        if let Some(desc) = fields.get("System.Description").and_then(|v| v.as_str()) {
-            println!("\n📄 Description:");
-            println!("{desc}");
-        }
+fn work_items_heading(assigned_to_me: bool) -> &'static str {
+    if assigned_to_me {
+        "My Work Items"
+    } else {
+        "Work Items"
     }
 }
 
-fn display_work_items_list(work_items: &[models::WorkItem]) {
+fn display_work_items_list(work_items: &[models::WorkItem], assigned_to_me: bool) {
     println!();
-    println!("📋 My Work Items ({} items)", work_items.len());
+    println!(
+        "📋 {} ({} items)",
+        work_items_heading(assigned_to_me),
+        work_items.len()
+    );
     let separator = "=".repeat(80);
     println!("{separator}");
     println!("{:<8} {:<15} {:<20} {:<30}", "ID", "Type", "State", "Title");
@@ -419,27 +1222,53 @@ fn display_work_items_list(work_items: &[models::WorkItem]) {
 
 async fn list_my_work_items(
     project: &str,
-    state_filter: Option<&str>,
-    work_item_type_filter: Option<&str>,
+    filters: &WorkItemFilters<'_>,
     limit: i32,
 ) -> Result<()> {
     match get_credentials() {
         Ok(creds) => {
             let client = create_client()?;
 
-            println!("📋 Listing work items assigned to you in project: {project}");
+            if filters.is_assigned_to_me() {
+                println!("📋 Listing work items assigned to you in project: {project}");
+            } else {
+                println!("📋 Listing work items in project: {project}");
+                if let Some(assignee) = filters.assigned_to {
+                    println!("🔍 Filtering by assignee: {assignee}");
+                }
+            }
 
-            if let Some(state) = state_filter {
+            if let Some(state) = filters.state {
                 println!("🔍 Filtering by state: {state}");
             }
 
-            if let Some(wit_type) = work_item_type_filter {
+            if let Some(wit_type) = filters.work_item_type {
                 println!("🔍 Filtering by type: {wit_type}");
             }
 
+            if let Some(area) = filters.area {
+                println!("🔍 Filtering by area: {area}");
+            }
+
+            if let Some(iteration) = filters.iteration {
+                println!("🔍 Filtering by iteration: {iteration}");
+            }
+
+            if !filters.tags.is_empty() {
+                println!("🔍 Filtering by tags: {}", filters.tags.join(", "));
+            }
+
+            if let Some(created_by) = filters.created_by {
+                println!("🔍 Filtering by creator: {created_by}");
+            }
+
+            if let Some(priority) = filters.priority {
+                println!("🔍 Filtering by priority: {priority}");
+            }
+
             println!("📊 Limit: {limit} items");
 
-            let wiql_query = build_wiql_query(project, state_filter, work_item_type_filter);
+            let wiql_query = build_wiql_query(project, filters);
 
             // Create WIQL request body
             let wiql_request = models::Wiql {
@@ -453,12 +1282,13 @@ async fn list_my_work_items(
                     project.to_string(),
                     String::new(),
                 )
+                .top(limit)
                 .await
             {
                 Ok(query_result) => {
                     let work_items = query_result.work_items;
                     if work_items.is_empty() {
-                        display_empty_work_items_table();
+                        display_empty_work_items_table(filters.is_assigned_to_me());
                         return Ok(());
                     }
 
@@ -466,32 +1296,26 @@ async fn list_my_work_items(
                     let limited_items: Vec<_> =
                         work_items.into_iter().take(limit as usize).collect();
 
-                    // Get detailed work item information by calling get_work_item for each ID
-                    let mut detailed_work_items = Vec::new();
-                    for work_item_ref in &limited_items {
-                        if let Some(id) = work_item_ref.id {
-                            match client
-                                .work_items_client()
-                                .get_work_item(creds.organization.clone(), id, project)
-                                .await
-                            {
-                                Ok(detailed_item) => detailed_work_items.push(detailed_item),
-                                Err(e) => {
-                                    eprintln!("❌ Failed to get details for work item {id}: {e}")
-                                }
-                            }
-                        }
-                    }
+                    let ids: Vec<i32> = limited_items.iter().filter_map(|item| item.id).collect();
+                    let fields = LIST_FIELDS.map(String::from);
+                    let detailed_work_items = get_work_items_details(
+                        &client,
+                        &creds.organization,
+                        project,
+                        &ids,
+                        &fields,
+                    )
+                    .await;
 
                     if detailed_work_items.is_empty() {
-                        display_empty_work_items_table();
+                        display_empty_work_items_table(filters.is_assigned_to_me());
                     } else {
-                        display_work_items_list(&detailed_work_items);
+                        display_work_items_list(&detailed_work_items, filters.is_assigned_to_me());
                     }
                 }
                 Err(e) => {
                     eprintln!("❌ Failed to execute WIQL query: {e}");
-                    display_empty_work_items_table();
+                    display_empty_work_items_table(filters.is_assigned_to_me());
                 }
             }
 
@@ -505,47 +1329,112 @@ async fn list_my_work_items(
 }
 
 /// Sanitizes a string for use in WIQL queries by escaping single quotes
-fn sanitize_wiql_value(value: &str) -> String {
+pub fn sanitize_wiql_value(value: &str) -> String {
     value.replace('\'', "''")
 }
 
-fn build_wiql_query(
-    project: &str,
-    state_filter: Option<&str>,
-    work_item_type_filter: Option<&str>,
-) -> String {
+/// Builds an identity clause, mapping 'me' to the @Me macro
+fn identity_clause(field: &str, value: &str) -> String {
+    if value.eq_ignore_ascii_case("me") {
+        format!(" AND [{field}] = @Me")
+    } else {
+        format!(" AND [{field}] = '{}'", sanitize_wiql_value(value))
+    }
+}
+
+fn build_wiql_query(project: &str, filters: &WorkItemFilters) -> String {
     let sanitized_project = sanitize_wiql_value(project);
     let mut wiql_query = format!(
-        "SELECT [System.Id], [System.Title], [System.State], [System.WorkItemType], [System.AssignedTo], [System.CreatedDate], [Microsoft.VSTS.Common.Priority] FROM WorkItems WHERE [System.TeamProject] = '{sanitized_project}' AND [System.AssignedTo] = @Me"
+        "SELECT [System.Id], [System.Title], [System.State], [System.WorkItemType], [System.AssignedTo], [System.CreatedDate], [Microsoft.VSTS.Common.Priority] FROM WorkItems WHERE [System.TeamProject] = '{sanitized_project}'"
     );
 
+    // Add assignee filter if provided
+    match filters.assigned_to {
+        None => {}
+        Some(a) if a.eq_ignore_ascii_case("any") => {}
+        Some(a) if a.eq_ignore_ascii_case("unassigned") => {
+            wiql_query.push_str(" AND [System.AssignedTo] = ''");
+        }
+        Some(assignee) => wiql_query.push_str(&identity_clause("System.AssignedTo", assignee)),
+    }
+
     // Add state filter if provided
-    if let Some(state) = state_filter {
+    if let Some(state) = filters.state {
         let sanitized_state = sanitize_wiql_value(state);
         wiql_query.push_str(&format!(" AND [System.State] = '{sanitized_state}'"));
     }
 
     // Add work item type filter if provided
-    if let Some(wit_type) = work_item_type_filter {
+    if let Some(wit_type) = filters.work_item_type {
         let sanitized_wit_type = sanitize_wiql_value(wit_type);
         wiql_query.push_str(&format!(
             " AND [System.WorkItemType] = '{sanitized_wit_type}'"
         ));
     }
 
-    wiql_query.push_str(" ORDER BY [System.CreatedDate] DESC");
+    if let Some(area) = filters.area {
+        let sanitized_area = sanitize_wiql_value(area);
+        wiql_query.push_str(&format!(" AND [System.AreaPath] UNDER '{sanitized_area}'"));
+    }
+
+    if let Some(iteration) = filters.iteration {
+        if iteration.eq_ignore_ascii_case("@current") {
+            wiql_query.push_str(" AND [System.IterationPath] = @CurrentIteration");
+        } else {
+            let sanitized_iteration = sanitize_wiql_value(iteration);
+            wiql_query.push_str(&format!(
+                " AND [System.IterationPath] UNDER '{sanitized_iteration}'"
+            ));
+        }
+    }
+
+    for tag in filters.tags {
+        let sanitized_tag = sanitize_wiql_value(tag);
+        wiql_query.push_str(&format!(" AND [System.Tags] CONTAINS '{sanitized_tag}'"));
+    }
+
+    if let Some(created_by) = filters.created_by {
+        wiql_query.push_str(&identity_clause("System.CreatedBy", created_by));
+    }
+
+    match filters.changed_since {
+        Some(ChangedSince::Date(date)) => wiql_query.push_str(&format!(
+            " AND [System.ChangedDate] >= '{}'",
+            date.format("%Y-%m-%d")
+        )),
+        Some(ChangedSince::DaysAgo(0)) => {
+            wiql_query.push_str(" AND [System.ChangedDate] >= @Today");
+        }
+        Some(ChangedSince::DaysAgo(days)) => {
+            wiql_query.push_str(&format!(" AND [System.ChangedDate] >= @Today - {days}"));
+        }
+        None => {}
+    }
+
+    if let Some(priority) = filters.priority {
+        wiql_query.push_str(&format!(
+            " AND [Microsoft.VSTS.Common.Priority] = {priority}"
+        ));
+    }
+
+    let order_by = filters.sort.unwrap_or(WorkItemSort::Created).order_by();
+    wiql_query.push_str(&format!(" ORDER BY {order_by}"));
     wiql_query
 }
 
-fn display_empty_work_items_table() {
+fn display_empty_work_items_table(assigned_to_me: bool) {
     println!();
-    println!("📋 My Work Items (0 items)");
+    println!("📋 {} (0 items)", work_items_heading(assigned_to_me));
     let separator = "=".repeat(80);
     println!("{separator}");
     println!("{:<8} {:<15} {:<20} {:<30}", "ID", "Type", "State", "Title");
     let dash_separator = "-".repeat(80);
     println!("{dash_separator}");
-    println!("No work items found assigned to you.");
+    if assigned_to_me {
+        println!("No work items found assigned to you.");
+    } else {
+        println!("No work items found matching the filters.");
+    }
     println!();
     println!("💡 Use 'azdocli boards work-item show --id <ID>' for detailed information");
     println!("💡 Use 'azdocli boards work-item show --id <ID> --web' to open in browser");
@@ -555,6 +1444,113 @@ pub async fn handle_command(subcommand: &BoardsSubCommands) -> Result<()> {
     let _credentials = get_credentials()?;
     match subcommand {
         BoardsSubCommands::WorkItem { subcommand } => handle_work_item_command(subcommand).await,
+        BoardsSubCommands::Query {
+            wiql,
+            file,
+            saved,
+            fields,
+            project,
+            limit,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let source = match (wiql, file, saved) {
+                (Some(wiql), _, _) => QuerySource::Wiql(wiql),
+                (_, Some(file), _) => QuerySource::File(file),
+                (_, _, Some(saved)) => QuerySource::Saved(saved),
+                _ => return Err(anyhow!("One of --wiql, --file or --saved is required")),
+            };
+
+            match query::run_query(&project_name, source, fields, *limit).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    eprintln!("❌ Failed to run query: {e}");
+                    Err(e)
+                }
+            }
+        }
+        BoardsSubCommands::Board { subcommand } => kanban::handle_command(subcommand).await,
+        BoardsSubCommands::Sprint {
+            team,
+            iteration,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let team = sprint::team_or_default(&project_name, team.as_deref()).await?;
+            match sprint::show_sprint(&project_name, &team, iteration).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    eprintln!("❌ Failed to show sprint: {e}");
+                    Err(e)
+                }
+            }
+        }
+        BoardsSubCommands::Capacity {
+            team,
+            iteration,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let team = sprint::team_or_default(&project_name, team.as_deref()).await?;
+            match capacity::show_capacity(&project_name, &team, iteration).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    eprintln!("❌ Failed to show capacity: {e}");
+                    Err(e)
+                }
+            }
+        }
+        BoardsSubCommands::Burndown {
+            team,
+            iteration,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let team = sprint::team_or_default(&project_name, team.as_deref()).await?;
+            match burndown::show_burndown(&project_name, &team, iteration).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    eprintln!("❌ Failed to show burndown: {e}");
+                    Err(e)
+                }
+            }
+        }
+        BoardsSubCommands::Roadmap {
+            area,
+            quarter,
+            format,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            match roadmap::show_roadmap(&project_name, area, quarter.as_deref(), *format).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    eprintln!("❌ Failed to show roadmap: {e}");
+                    Err(e)
+                }
+            }
+        }
+        BoardsSubCommands::Watch {
+            wiql,
+            interval,
+            notify,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            match watch::watch(&project_name, wiql.as_deref(), *interval, notify.as_deref()).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    eprintln!("❌ Failed to watch work items: {e}");
+                    Err(e)
+                }
+            }
+        }
+        BoardsSubCommands::Tags { subcommand } => tags::handle_command(subcommand).await,
+        BoardsSubCommands::Areas { subcommand } => {
+            classification::handle_area_command(subcommand).await
+        }
+        BoardsSubCommands::Iterations { subcommand } => {
+            classification::handle_iteration_command(subcommand).await
+        }
     }
 }
 
@@ -563,16 +1559,55 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
         WorkItemSubCommands::Create {
             work_item_type,
             title,
+            template,
+            vars,
+            branch,
+            repo,
             project,
         } => {
             let project_name = get_project_or_default(project.as_deref())?;
-            println!("Creating a {work_item_type:?} work item in project: {project_name}");
+            let new_work_item = match resolve_new_work_item(
+                work_item_type.as_ref(),
+                title,
+                template.as_deref(),
+                vars,
+            ) {
+                Ok(new_work_item) => new_work_item,
+                Err(e) => {
+                    eprintln!("❌ Failed to create work item: {e}");
+                    return Err(e);
+                }
+            };
+            let new_branch = match (branch, repo) {
+                (Some(branch), Some(repo)) => {
+                    match prepare_linked_branch(&project_name, repo, branch).await {
+                        Ok(new_branch) => Some(new_branch),
+                        Err(e) => {
+                            eprintln!("❌ Failed to create work item: {e}");
+                            return Err(e);
+                        }
+                    }
+                }
+                _ => None,
+            };
+            println!(
+                "Creating a {} work item in project: {}",
+                new_work_item.work_item_type, project_name
+            );
 
-            match create_work_item(&project_name, work_item_type, title).await {
+            match create_work_item(
+                &project_name,
+                &new_work_item.work_item_type,
+                &new_work_item.title,
+                &new_work_item.fields,
+                None,
+            )
+            .await
+            {
                 Ok(work_item) => {
                     println!("{}", "✅ Work item created successfully!".green());
                     println!("Created work item with ID: {}", work_item.id);
-                    println!("Title: {title}");
+                    println!("Title: {}", new_work_item.title);
                     if let Some(fields) = work_item.fields.as_object() {
                         if let Some(desc) =
                             fields.get("System.Description").and_then(|v| v.as_str())
@@ -580,6 +1615,15 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
                             println!("Description: {desc}");
                         }
                     }
+
+                    if let Some(new_branch) = new_branch {
+                        if let Err(e) =
+                            create_linked_branch(&project_name, work_item.id, new_branch).await
+                        {
+                            eprintln!("❌ Failed to create branch: {e}");
+                            return Err(e);
+                        }
+                    }
                 }
                 Err(e) => {
                     eprintln!("❌ Failed to create work item: {e}");
@@ -587,6 +1631,103 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
                 }
             }
         }
+        WorkItemSubCommands::Import {
+            file,
+            mappings,
+            default_type,
+            types,
+            parent,
+            dry_run,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            println!("Importing work items from {file} into project: {project_name}");
+
+            if let Err(e) = import::import_work_items(
+                &project_name,
+                file,
+                mappings,
+                default_type,
+                types,
+                parent.as_deref(),
+                *dry_run,
+            )
+            .await
+            {
+                eprintln!("❌ Failed to import work items: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::Export {
+            wiql,
+            format,
+            fields,
+            output,
+            with_relations,
+            with_history,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            println!("Exporting work items from project: {project_name}");
+
+            if let Err(e) = export::export_work_items(
+                &project_name,
+                wiql,
+                *format,
+                fields,
+                output.as_deref(),
+                *with_relations,
+                *with_history,
+            )
+            .await
+            {
+                eprintln!("❌ Failed to export work items: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::BulkUpdate {
+            wiql,
+            changes,
+            dry_run,
+            yes,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            println!("Bulk updating work items in project: {project_name}");
+
+            if let Err(e) = bulk::bulk_update(&project_name, wiql, changes, *dry_run, *yes).await {
+                eprintln!("❌ Failed to bulk update work items: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::Template { subcommand } => {
+            if let Err(e) = templates::handle_command(subcommand).await {
+                eprintln!("❌ Template command failed: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::RecycleBin { subcommand } => {
+            recycle_bin::handle_command(subcommand).await?;
+        }
+        WorkItemSubCommands::History { id, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            if let Err(e) = history::show_history(&project_name, id).await {
+                eprintln!("❌ Failed to get work item history: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::Diff { id, revs, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let [old_rev, new_rev] = revs[..] else {
+                let e = anyhow!("Exactly two revisions are required, e.g. --rev 3 --rev 7");
+                eprintln!("❌ Failed to compare revisions: {e}");
+                return Err(e);
+            };
+            if let Err(e) = history::show_diff(&project_name, id, old_rev, new_rev).await {
+                eprintln!("❌ Failed to compare revisions: {e}");
+                return Err(e);
+            }
+        }
         WorkItemSubCommands::Delete {
             id,
             project,
@@ -605,11 +1746,14 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
                     if *soft_delete {
                         println!(
                             "{}",
-                            "✅ Work item soft deleted successfully (state changed to 'Removed')"
+                            "✅ Work item soft deleted successfully (state changed to its Removed state)"
                                 .green()
                         );
                     } else {
                         println!("{}", "✅ Work item deleted successfully".green());
+                        println!(
+                            "The work item was moved to the recycle bin. Restore it with: azdocli boards work-item recycle-bin restore --id {id}"
+                        );
                     }
                 }
                 Err(e) => {
@@ -622,18 +1766,31 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
             project,
             state,
             work_item_type,
+            assigned_to,
+            area,
+            iteration,
+            tag,
+            created_by,
+            changed_since,
+            priority,
+            sort,
             limit,
         } => {
             let project_name = get_project_or_default(project.as_deref())?;
-
-            match list_my_work_items(
-                &project_name,
-                state.as_deref(),
-                work_item_type.as_deref(),
-                *limit,
-            )
-            .await
-            {
+            let filters = WorkItemFilters {
+                state: state.as_deref(),
+                work_item_type: work_item_type.as_deref(),
+                assigned_to: Some(assigned_to.as_str()),
+                area: area.as_deref(),
+                iteration: iteration.as_deref(),
+                tags: tag,
+                created_by: created_by.as_deref(),
+                changed_since: changed_since.as_ref(),
+                priority: *priority,
+                sort: Some(*sort),
+            };
+
+            match list_my_work_items(&project_name, &filters, *limit).await {
                 Ok(_) => {
                     println!("{}", "✅ Work items listed successfully!".green());
                 }
@@ -643,30 +1800,30 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
                 }
             }
         }
-        WorkItemSubCommands::Show { id, project, web } => {
+        WorkItemSubCommands::Show {
+            id,
+            project,
+            web,
+            fields,
+        } => {
             let project_name = get_project_or_default(project.as_deref())?;
             println!("Showing work item with id: {id} in project: {project_name}");
 
             // Open in browser if requested
             if *web {
-                match get_credentials() {
-                    Ok(creds) => {
-                        if let Err(e) = open_work_item_in_browser(&creds.organization, id) {
-                            eprintln!("❌ Failed to open work item in browser: {e}");
-                        }
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        eprintln!("❌ Failed to get credentials: {e}");
-                        return Err(e);
-                    }
+                if let Err(e) = open_work_item_in_browser(&project_name, id) {
+                    eprintln!("❌ Failed to open work item in browser: {e}");
+                    return Err(e);
                 }
+                return Ok(());
             }
 
             // Otherwise show in terminal
-            match get_work_item(&project_name, id).await {
+            match get_work_item(&project_name, id, Some("relations")).await {
                 Ok(work_item) => {
-                    display_work_item(&work_item);
+                    let relations =
+                        relations::resolve_relations(&project_name, &work_item.relations).await;
+                    display_work_item(&work_item, &relations, fields.as_deref());
                 }
                 Err(e) => {
                     eprintln!("❌ Failed to retrieve work item: {e}");
@@ -681,20 +1838,20 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
             description,
             state,
             priority,
+            tags,
+            expect_rev,
         } => {
             let project_name = get_project_or_default(project.as_deref())?;
             println!("Updating work item with id: {id} in project: {project_name}");
 
-            match update_work_item(
-                &project_name,
-                id,
-                title.as_deref(),
-                description.as_deref(),
-                state.as_deref(),
-                *priority,
-            )
-            .await
-            {
+            let changes = WorkItemChanges {
+                title: title.as_deref(),
+                description: description.as_deref(),
+                state: state.as_deref(),
+                priority: *priority,
+                tags,
+            };
+            match update_work_item(&project_name, id, &changes, *expect_rev).await {
                 Ok(work_item) => {
                     println!("{}", "✅ Work item updated successfully!".green());
                     println!("Updated work item with ID: {}", work_item.id);
@@ -709,6 +1866,13 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
                         {
                             println!("Updated Description: {updated_desc}");
                         }
+                        if !tags.is_empty() {
+                            let updated_tags = fields
+                                .get("System.Tags")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default();
+                            println!("Updated Tags: {updated_tags}");
+                        }
                     }
                 }
                 Err(e) => {
@@ -717,6 +1881,78 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
                 }
             }
         }
+        WorkItemSubCommands::Attach {
+            id,
+            project,
+            comment,
+            files,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            println!(
+                "Attaching {} file(s) to work item {id} in project: {project_name}",
+                files.len()
+            );
+
+            match attachments::attach_files(&project_name, id, files, comment.as_deref()).await {
+                Ok(work_item) => {
+                    println!("{}", "✅ Files attached successfully!".green());
+                    if let Some(rev) = work_item.rev {
+                        println!("Work item {} is now at revision {rev}", work_item.id);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to attach files: {e}");
+                    return Err(e);
+                }
+            }
+        }
+        WorkItemSubCommands::Link {
+            id,
+            pr,
+            commit,
+            branch,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let artifacts: Vec<&ArtifactRef> = pr.iter().chain(commit).chain(branch).collect();
+            if let Err(e) = link_work_item(&project_name, id, &artifacts).await {
+                eprintln!("❌ Failed to link work item: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::Follow { id, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            if let Err(e) = watch::follow(&project_name, id).await {
+                eprintln!("❌ Failed to follow work item: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::Unfollow { id, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            if let Err(e) = watch::unfollow(&project_name, id) {
+                eprintln!("❌ Failed to unfollow work item: {e}");
+                return Err(e);
+            }
+        }
+        WorkItemSubCommands::Attachments {
+            id,
+            project,
+            subcommand,
+        } => match subcommand {
+            Some(subcommand) => attachments::handle_command(subcommand).await?,
+            None => {
+                let id = id
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("The --id argument is required"))?;
+                let project_name = get_project_or_default(project.as_deref())?;
+                println!("Listing attachments of work item {id} in project: {project_name}");
+
+                if let Err(e) = attachments::list_attachments(&project_name, id).await {
+                    eprintln!("❌ Failed to list attachments: {e}");
+                    return Err(e);
+                }
+            }
+        },
     }
     Ok(())
 }
@@ -725,6 +1961,41 @@ async fn handle_work_item_command(subcommand: &WorkItemSubCommands) -> Result<()
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_select_fields() {
+        let fields = json!({
+            "System.Title": "Checkout fails",
+            "System.AssignedTo": { "displayName": "Jane Doe", "uniqueName": "jane@contoso.com" },
+            "Microsoft.VSTS.Common.Priority": 2
+        });
+
+        assert_eq!(
+            select_fields(
+                &fields,
+                "assignedto, Microsoft.VSTS.Common.Priority,Missing"
+            ),
+            vec![
+                ("System.AssignedTo".to_string(), "Jane Doe".to_string()),
+                (
+                    "Microsoft.VSTS.Common.Priority".to_string(),
+                    "2".to_string()
+                ),
+                ("Missing".to_string(), "(not set)".to_string()),
+            ]
+        );
+        assert_eq!(
+            select_fields(&fields, "all")
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            vec![
+                "Microsoft.VSTS.Common.Priority",
+                "System.AssignedTo",
+                "System.Title"
+            ]
+        );
+    }
+
     #[test]
     fn test_sanitize_wiql_value_escapes_single_quotes() {
         assert_eq!(sanitize_wiql_value("test'value"), "test''value");
@@ -750,20 +2021,169 @@ mod tests {
 
     #[test]
     fn test_build_wiql_query_sanitizes_project() {
-        let query = build_wiql_query("test'project", None, None);
+        let query = build_wiql_query("test'project", &WorkItemFilters::default());
         assert!(query.contains("test''project"));
         assert!(!query.contains("test'project' AND"));
     }
 
     #[test]
     fn test_build_wiql_query_sanitizes_state() {
-        let query = build_wiql_query("project", Some("Active'Hack"), None);
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                state: Some("Active'Hack"),
+                ..Default::default()
+            },
+        );
         assert!(query.contains("Active''Hack"));
     }
 
     #[test]
     fn test_build_wiql_query_sanitizes_work_item_type() {
-        let query = build_wiql_query("project", None, Some("Bug'Injection"));
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                work_item_type: Some("Bug'Injection"),
+                ..Default::default()
+            },
+        );
         assert!(query.contains("Bug''Injection"));
     }
+
+    #[test]
+    fn test_build_wiql_query_assigned_to_variants() {
+        let me = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                assigned_to: Some("me"),
+                ..Default::default()
+            },
+        );
+        assert!(me.contains("[System.AssignedTo] = @Me"));
+
+        let unassigned = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                assigned_to: Some("unassigned"),
+                ..Default::default()
+            },
+        );
+        assert!(unassigned.contains("[System.AssignedTo] = ''"));
+
+        let any = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                assigned_to: Some("any"),
+                ..Default::default()
+            },
+        );
+        assert!(!any.contains("[System.AssignedTo] ="));
+    }
+
+    #[test]
+    fn test_build_wiql_query_sanitizes_assigned_to() {
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                assigned_to: Some("O'Brien"),
+                ..Default::default()
+            },
+        );
+        assert!(query.contains("[System.AssignedTo] = 'O''Brien'"));
+    }
+
+    #[test]
+    fn test_build_wiql_query_sanitizes_area_and_iteration() {
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                area: Some("Team'Area"),
+                iteration: Some("Sprint' OR 1=1"),
+                ..Default::default()
+            },
+        );
+        assert!(query.contains("[System.AreaPath] UNDER 'Team''Area'"));
+        assert!(query.contains("[System.IterationPath] UNDER 'Sprint'' OR 1=1'"));
+    }
+
+    #[test]
+    fn test_build_wiql_query_current_iteration() {
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                iteration: Some("@current"),
+                ..Default::default()
+            },
+        );
+        assert!(query.contains("[System.IterationPath] = @CurrentIteration"));
+    }
+
+    #[test]
+    fn test_build_wiql_query_sanitizes_tags_and_created_by() {
+        let tags = vec!["urgent".to_string(), "it's".to_string()];
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                tags: &tags,
+                created_by: Some("Bob'; DROP"),
+                ..Default::default()
+            },
+        );
+        assert!(query.contains("[System.Tags] CONTAINS 'urgent'"));
+        assert!(query.contains("[System.Tags] CONTAINS 'it''s'"));
+        assert!(query.contains("[System.CreatedBy] = 'Bob''; DROP'"));
+    }
+
+    #[test]
+    fn test_build_wiql_query_changed_since_priority_and_sort() {
+        let since = ChangedSince::DaysAgo(7);
+        let query = build_wiql_query(
+            "project",
+            &WorkItemFilters {
+                changed_since: Some(&since),
+                priority: Some(1),
+                sort: Some(WorkItemSort::Changed),
+                ..Default::default()
+            },
+        );
+        assert!(query.contains("[System.ChangedDate] >= @Today - 7"));
+        assert!(query.contains("[Microsoft.VSTS.Common.Priority] = 1"));
+        assert!(query.ends_with("ORDER BY [System.ChangedDate] DESC"));
+    }
+
+    #[test]
+    fn test_parse_changed_since() {
+        // 2026-10-19 is a Monday
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2026, 10, 21).unwrap();
+        let parse = |value| ChangedSince::parse(value, wednesday);
+
+        assert_eq!(parse("today"), Ok(ChangedSince::DaysAgo(0)));
+        assert_eq!(parse("Yesterday"), Ok(ChangedSince::DaysAgo(1)));
+        assert_eq!(
+            ChangedSince::parse("yesterday", monday),
+            Ok(ChangedSince::DaysAgo(3))
+        );
+        assert_eq!(parse("14d"), Ok(ChangedSince::DaysAgo(14)));
+        assert_eq!(parse("3D"), Ok(ChangedSince::DaysAgo(3)));
+        assert_eq!(
+            parse("2026-01-31"),
+            Ok(ChangedSince::Date(
+                NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()
+            ))
+        );
+        assert!(parse("2026-01-31' OR 1=1").is_err());
+        assert!(parse("xd").is_err());
+        assert!(parse("-3d").is_err());
+        assert!(parse("last week").is_err());
+
+        assert_eq!(
+            ChangedSince::DaysAgo(3).date(monday),
+            NaiveDate::from_ymd_opt(2026, 10, 16).unwrap()
+        );
+        assert_eq!(
+            ChangedSince::DaysAgo(7).date(monday),
+            NaiveDate::from_ymd_opt(2026, 10, 12).unwrap()
+        );
+    }
 }
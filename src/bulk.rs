@@ -0,0 +1,417 @@
+use crate::auth::get_credentials;
+use crate::boards::{create_client, get_work_items_details};
+use crate::query::{self, format_field_value};
+use crate::web;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::json_patch_operation::Op;
+use azure_devops_rust_api::wit::models::{JsonPatchOperation, WorkItem};
+use colored::Colorize;
+use dialoguer::Confirm;
+use serde_json::{json, Value};
+
+/// Maximum number of updates the `$batch` endpoint accepts per request
+const BATCH_SIZE: usize = 200;
+
+/// Number of work items listed in the confirmation summary
+const SUMMARY_LIMIT: usize = 20;
+
+pub const TAGS_FIELD: &str = "System.Tags";
+
+/// A change requested with `--set`
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldChange {
+    /// `Field=Value`
+    Set { field: String, value: String },
+    /// `System.Tags+=tag`
+    AddTag(String),
+    /// `System.Tags-=tag`
+    RemoveTag(String),
+}
+
+impl std::fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldChange::Set { field, value } => write!(f, "set {field} = '{value}'"),
+            FieldChange::AddTag(tag) => write!(f, "add tag '{tag}'"),
+            FieldChange::RemoveTag(tag) => write!(f, "remove tag '{tag}'"),
+        }
+    }
+}
+
+/// Parses `Field=Value`, `System.Tags+=tag` and `System.Tags-=tag`
+pub fn parse_field_change(value: &str) -> Result<FieldChange, String> {
+    let (field, new_value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid change '{value}', expected 'Field=Value'"))?;
+    let new_value = new_value.trim().to_string();
+
+    let tag_change = |field: &str, change: fn(String) -> FieldChange| {
+        if field.trim() != TAGS_FIELD {
+            return Err(format!("'+=' and '-=' are only supported for {TAGS_FIELD}"));
+        }
+        if new_value.is_empty() {
+            return Err(format!("Missing tag in '{value}'"));
+        }
+        Ok(change(new_value.clone()))
+    };
+
+    if let Some(field) = field.strip_suffix('+') {
+        tag_change(field, FieldChange::AddTag)
+    } else if let Some(field) = field.strip_suffix('-') {
+        tag_change(field, FieldChange::RemoveTag)
+    } else if field.trim().is_empty() {
+        Err(format!("Missing field name in '{value}'"))
+    } else {
+        Ok(FieldChange::Set {
+            field: field.trim().to_string(),
+            value: new_value,
+        })
+    }
+}
+
+/// Parses a tag edit given with `--tag`: '+tag' adds the tag and '-tag' removes it
+pub fn parse_tag_edit(value: &str) -> Result<FieldChange, String> {
+    let (change, tag): (fn(String) -> FieldChange, &str) = match value.trim() {
+        tag if tag.starts_with('+') => (FieldChange::AddTag, &tag[1..]),
+        tag if tag.starts_with('-') => (FieldChange::RemoveTag, &tag[1..]),
+        tag => (FieldChange::AddTag, tag),
+    };
+    let tag = tag.trim();
+    if tag.is_empty() || tag.contains(';') {
+        return Err(format!("Invalid tag '{value}', expected '+tag' or '-tag'"));
+    }
+    Ok(change(tag.to_string()))
+}
+
+/// Adds and removes tags in a semicolon separated `System.Tags` value
+pub fn apply_tag_changes(current: &str, add: &[&str], remove: &[&str]) -> String {
+    let mut tags: Vec<String> = current
+        .split(';')
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect();
+
+    for tag in add {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags.retain(|t| !remove.iter().any(|r| t.eq_ignore_ascii_case(r)));
+
+    tags.join("; ")
+}
+
+/// Builds the patch that applies the changes to one work item
+pub fn patch_operations(work_item: &WorkItem, changes: &[FieldChange]) -> Vec<JsonPatchOperation> {
+    let mut patch_operations: Vec<JsonPatchOperation> = changes
+        .iter()
+        .filter_map(|change| match change {
+            FieldChange::Set { field, value } => Some(JsonPatchOperation {
+                from: None,
+                op: Some(Op::Add),
+                path: Some(format!("/fields/{field}")),
+                value: Some(json!(value)),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let add: Vec<&str> = changes
+        .iter()
+        .filter_map(|c| match c {
+            FieldChange::AddTag(tag) => Some(tag.as_str()),
+            _ => None,
+        })
+        .collect();
+    let remove: Vec<&str> = changes
+        .iter()
+        .filter_map(|c| match c {
+            FieldChange::RemoveTag(tag) => Some(tag.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    if !add.is_empty() || !remove.is_empty() {
+        let current = work_item
+            .fields
+            .get(TAGS_FIELD)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        patch_operations.push(JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some(format!("/fields/{TAGS_FIELD}")),
+            value: Some(json!(apply_tag_changes(current, &add, &remove))),
+        });
+    }
+
+    patch_operations
+}
+
+fn title(work_item: &WorkItem) -> String {
+    work_item
+        .fields
+        .get("System.Title")
+        .map(format_field_value)
+        .unwrap_or_default()
+}
+
+fn display_summary(work_items: &[WorkItem], changes: &[FieldChange]) {
+    println!();
+    println!("{}", "Changes:".bold());
+    for change in changes {
+        println!("  • {change}");
+    }
+    println!();
+    println!("{}", format!("Work items ({}):", work_items.len()).bold());
+    for work_item in work_items.iter().take(SUMMARY_LIMIT) {
+        println!("  #{:<8} {}", work_item.id, title(work_item));
+    }
+    if work_items.len() > SUMMARY_LIMIT {
+        println!("  ... and {} more", work_items.len() - SUMMARY_LIMIT);
+    }
+    println!();
+}
+
+/// Builds the body of a `$batch` request that applies each patch to its work item
+fn batch_request(updates: &[(i32, Vec<JsonPatchOperation>)]) -> Value {
+    Value::Array(
+        updates
+            .iter()
+            .map(|(id, patch)| {
+                json!({
+                    "method": "PATCH",
+                    "uri": format!("/_apis/wit/workitems/{id}?api-version=7.1"),
+                    "headers": { "Content-Type": "application/json-patch+json" },
+                    "body": patch,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Reads the outcome of each update from a `$batch` response, in request order
+fn batch_results(response: &Value, count: usize) -> Vec<Result<(), String>> {
+    let responses = response["value"].as_array().cloned().unwrap_or_default();
+    (0..count)
+        .map(|index| {
+            let Some(item) = responses.get(index) else {
+                return Err("missing from the batch response".to_string());
+            };
+            match item["code"].as_i64() {
+                Some(code) if (200..300).contains(&code) => Ok(()),
+                code => {
+                    // The body of each response is JSON serialized as a string
+                    let body = item["body"].as_str().unwrap_or_default();
+                    let message = serde_json::from_str::<Value>(body)
+                        .ok()
+                        .and_then(|b| b["message"].as_str().map(String::from))
+                        .unwrap_or_else(|| body.to_string());
+                    Err(format!("HTTP {}: {message}", code.unwrap_or_default()))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Sends up to `BATCH_SIZE` updates in one `$batch` request
+async fn send_batch(
+    client: &reqwest::Client,
+    pat: &str,
+    updates: &[(i32, Vec<JsonPatchOperation>)],
+) -> Result<Vec<Result<(), String>>> {
+    let mut url = web::api_url(None, &["wit", "$batch"])?;
+    url.query_pairs_mut().append_pair("api-version", "7.1");
+
+    let response = client
+        .post(url)
+        .basic_auth("", Some(pat))
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_vec(&batch_request(updates))?)
+        .send()
+        .await?
+        .error_for_status()?;
+    let body: Value = serde_json::from_slice(&response.bytes().await?)?;
+    Ok(batch_results(&body, updates.len()))
+}
+
+/// Sends the updates through the work item `$batch` endpoint, `BATCH_SIZE` at a time,
+/// and reports the outcome of each work item. Returns the number of failed updates.
+///
+/// The generated client has no `$batch` endpoint, so the requests are sent directly.
+pub async fn send_updates(updates: Vec<(i32, Vec<JsonPatchOperation>)>) -> Result<usize> {
+    let creds = get_credentials()?;
+    let client = reqwest::Client::new();
+    let mut failures = 0;
+
+    for chunk in updates.chunks(BATCH_SIZE) {
+        let results = match send_batch(&client, &creds.pat, chunk).await {
+            Ok(results) => results,
+            Err(e) => vec![Err(e.to_string()); chunk.len()],
+        };
+        for ((id, _), result) in chunk.iter().zip(results) {
+            match result {
+                Ok(()) => println!("✅ Updated work item {id}"),
+                Err(e) => {
+                    eprintln!("❌ Failed to update work item {id}: {e}");
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    Ok(failures)
+}
+
+pub async fn bulk_update(
+    project: &str,
+    wiql: &str,
+    changes: &[FieldChange],
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<()> {
+    let result = query::execute_query(project, Some(wiql), None).await?;
+    let ids = query::result_ids(&result);
+    if ids.is_empty() {
+        println!("No work items matched the query.");
+        return Ok(());
+    }
+
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let fields = vec!["System.Title".to_string(), TAGS_FIELD.to_string()];
+    let work_items =
+        get_work_items_details(&client, &creds.organization, project, &ids, &fields).await;
+
+    display_summary(&work_items, changes);
+
+    let updates: Vec<(i32, Vec<JsonPatchOperation>)> = work_items
+        .iter()
+        .map(|wi| (wi.id, patch_operations(wi, changes)))
+        .collect();
+
+    if dry_run {
+        for (id, patch) in &updates {
+            println!("{}", format!("Work item {id}:").bold());
+            println!("{}", serde_json::to_string_pretty(patch)?);
+        }
+        println!();
+        println!("Dry run: {} work items would be updated", updates.len());
+        return Ok(());
+    }
+
+    if !skip_confirmation {
+        if !Confirm::new()
+            .with_prompt(format!(
+                "Are you sure you want to update {} work items?",
+                updates.len()
+            ))
+            .default(false)
+            .interact()?
+        {
+            println!("Bulk update cancelled.");
+            return Ok(());
+        }
+    } else {
+        println!("Proceeding with bulk update (confirmation skipped)...");
+    }
+
+    let total = updates.len();
+    let failures = send_updates(updates).await?;
+    println!();
+    if failures > 0 {
+        return Err(anyhow!(
+            "{} of {} work items were not updated",
+            failures,
+            total
+        ));
+    }
+    println!("{}", format!("✅ Updated {total} work items").green());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_request_and_results() {
+        let patch = vec![JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some("/fields/System.State".to_string()),
+            value: Some(json!("Closed")),
+        }];
+        let request = batch_request(&[(7, patch.clone()), (8, patch)]);
+        assert_eq!(request[0]["method"], "PATCH");
+        assert_eq!(request[1]["uri"], "/_apis/wit/workitems/8?api-version=7.1");
+        assert_eq!(request[0]["body"][0]["path"], "/fields/System.State");
+
+        let response = json!({
+            "count": 2,
+            "value": [
+                { "code": 200, "body": "{}" },
+                { "code": 400, "body": "{\"message\":\"The field 'State' is read only\"}" }
+            ]
+        });
+        assert_eq!(
+            batch_results(&response, 3),
+            vec![
+                Ok(()),
+                Err("HTTP 400: The field 'State' is read only".to_string()),
+                Err("missing from the batch response".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_field_change() {
+        assert_eq!(
+            parse_field_change("System.State=Closed"),
+            Ok(FieldChange::Set {
+                field: "System.State".to_string(),
+                value: "Closed".to_string()
+            })
+        );
+        assert_eq!(
+            parse_field_change("System.Tags+=cleanup"),
+            Ok(FieldChange::AddTag("cleanup".to_string()))
+        );
+        assert_eq!(
+            parse_field_change("System.Tags-=stale"),
+            Ok(FieldChange::RemoveTag("stale".to_string()))
+        );
+        assert!(parse_field_change("System.Title+=x").is_err());
+        assert!(parse_field_change("System.State").is_err());
+        assert!(parse_field_change("=Closed").is_err());
+    }
+
+    #[test]
+    fn test_parse_tag_edit() {
+        assert_eq!(
+            parse_tag_edit("+cleanup"),
+            Ok(FieldChange::AddTag("cleanup".to_string()))
+        );
+        assert_eq!(
+            parse_tag_edit("-stale"),
+            Ok(FieldChange::RemoveTag("stale".to_string()))
+        );
+        assert_eq!(
+            parse_tag_edit("release"),
+            Ok(FieldChange::AddTag("release".to_string()))
+        );
+        assert!(parse_tag_edit("+").is_err());
+        assert!(parse_tag_edit("+a;b").is_err());
+    }
+
+    #[test]
+    fn test_apply_tag_changes() {
+        assert_eq!(
+            apply_tag_changes("release; Stale;ui", &["cleanup", "UI"], &["stale"]),
+            "release; ui; cleanup"
+        );
+        assert_eq!(apply_tag_changes("", &["cleanup"], &[]), "cleanup");
+        assert_eq!(apply_tag_changes("stale", &[], &["stale"]), "");
+    }
+}
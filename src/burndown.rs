@@ -0,0 +1,326 @@
+use crate::auth::get_credentials;
+use crate::boards;
+use crate::history::{self, field_change};
+use crate::query;
+use crate::sprint;
+use crate::states;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItemUpdate;
+use chrono::{Datelike, Local, NaiveDate, NaiveTime, Utc, Weekday};
+use colored::Colorize;
+
+/// Height of the chart in lines
+const CHART_HEIGHT: usize = 12;
+
+/// The fields of a Task that matter for the burndown, as of one revision
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    pub date: NaiveDate,
+    pub remaining: f64,
+    pub state: String,
+    pub iteration: String,
+}
+
+/// Replays the updates of a work item into the values it had after each revision
+pub fn snapshots(updates: &[WorkItemUpdate]) -> Vec<Snapshot> {
+    let mut snapshots = Vec::new();
+    let mut remaining = 0.0;
+    let mut state = String::new();
+    let mut iteration = String::new();
+
+    for update in updates {
+        if let Some((_, new)) = field_change(update, "Microsoft.VSTS.Scheduling.RemainingWork") {
+            remaining = new.and_then(|v| v.as_f64()).unwrap_or(0.0);
+        }
+        for (field, value) in [
+            ("System.State", &mut state),
+            ("System.IterationPath", &mut iteration),
+        ] {
+            if let Some((_, new)) = field_change(update, field) {
+                *value = new.and_then(|v| v.as_str()).unwrap_or_default().to_string();
+            }
+        }
+
+        let date = field_change(update, "System.ChangedDate")
+            .and_then(|(_, new)| new?.as_str())
+            .and_then(sprint::parse_date);
+        if let Some(date) = date {
+            snapshots.push(Snapshot {
+                date,
+                remaining,
+                state: state.clone(),
+                iteration: iteration.clone(),
+            });
+        }
+    }
+
+    snapshots
+}
+
+/// Remaining work that counted towards the sprint at the end of `day`
+pub fn remaining_on(
+    snapshots: &[Snapshot],
+    day: NaiveDate,
+    iteration_path: &str,
+    closed_states: &[String],
+) -> f64 {
+    match snapshots.iter().rev().find(|s| s.date <= day) {
+        Some(snapshot)
+            if snapshot.iteration.eq_ignore_ascii_case(iteration_path)
+                && !closed_states.contains(&snapshot.state) =>
+        {
+            snapshot.remaining
+        }
+        _ => 0.0,
+    }
+}
+
+/// The end of `day` in local time as a UTC timestamp, the form WIQL's ASOF clause expects
+fn end_of_day_utc(day: NaiveDate) -> Option<String> {
+    let end = day.succ_opt()?.and_time(NaiveTime::MIN);
+    let end = end.and_local_timezone(Local).earliest()?;
+    Some(
+        end.with_timezone(&Utc)
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string(),
+    )
+}
+
+/// IDs of the Tasks that sat in the iteration at the end of any of `days`, so tasks
+/// moved out during the sprint still count for the days they were planned
+async fn get_past_task_ids(
+    project: &str,
+    iteration_path: &str,
+    days: &[NaiveDate],
+) -> Result<Vec<i32>> {
+    let mut ids = Vec::new();
+    for asof in days.iter().filter_map(|day| end_of_day_utc(*day)) {
+        let wiql = format!(
+            "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = '{}' AND [System.WorkItemType] = 'Task' AND [System.IterationPath] = '{}' ASOF '{}'",
+            boards::sanitize_wiql_value(project),
+            boards::sanitize_wiql_value(iteration_path),
+            asof
+        );
+        let result = query::execute_query(project, Some(&wiql), None).await?;
+        for id in query::result_ids(&result) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    Ok(ids)
+}
+
+/// Renders the actual remaining work as bars and the ideal trend as dots
+pub fn render_chart(actual: &[Option<f64>], ideal: &[f64], height: usize) -> Vec<String> {
+    let max = actual
+        .iter()
+        .flatten()
+        .chain(ideal)
+        .fold(0.0_f64, |max, v| max.max(*v));
+    let level = |value: f64| -> usize {
+        if max <= 0.0 {
+            0
+        } else {
+            (value / max * height as f64).round() as usize
+        }
+    };
+
+    let mut lines = Vec::with_capacity(height + 1);
+    for row in (1..=height).rev() {
+        let label = if row == height {
+            format!("{max:>7.1} h")
+        } else {
+            " ".repeat(9)
+        };
+        let mut line = format!("{label} │");
+        for (day, ideal) in ideal.iter().enumerate() {
+            let cell = match actual.get(day).copied().flatten() {
+                Some(value) if level(value) >= row => " ██",
+                _ if level(*ideal) == row => " ··",
+                _ => "   ",
+            };
+            line.push_str(cell);
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.push(format!("{:>7.1} h └{}", 0.0, "───".repeat(ideal.len())));
+    lines
+}
+
+pub async fn show_burndown(project: &str, team: &str, iteration: &str) -> Result<()> {
+    let iteration = sprint::resolve_iteration(project, team, iteration).await?;
+    let iteration_id = iteration
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Iteration ID is missing"))?;
+    let iteration_path = iteration.path.clone().unwrap_or_default();
+    let (start, finish) = sprint::iteration_dates(&iteration).ok_or_else(|| {
+        anyhow!(
+            "Iteration '{}' has no start and finish dates",
+            iteration_path
+        )
+    })?;
+
+    let (_, team_days_off) = sprint::get_member_capacities(project, team, &iteration_id).await?;
+    let days: Vec<NaiveDate> = start
+        .iter_days()
+        .take_while(|day| *day <= finish)
+        .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+        .filter(|day| sprint::working_days(*day, *day, &team_days_off) > 0)
+        .collect();
+    if days.is_empty() {
+        return Err(anyhow!(
+            "Iteration '{}' has no working days",
+            iteration_path
+        ));
+    }
+
+    // Tasks moved out of the sprint are no longer part of the iteration, so the
+    // iteration is also queried as it was at the end of each elapsed day
+    let today = Local::now().date_naive();
+    let elapsed: Vec<NaiveDate> = days.iter().copied().filter(|d| *d < today).collect();
+    let mut ids = sprint::get_iteration_work_item_ids(project, team, &iteration_id).await?;
+    for id in get_past_task_ids(project, &iteration_path, &elapsed).await? {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    let creds = get_credentials()?;
+    let fields = vec!["System.WorkItemType".to_string()];
+    let task_ids: Vec<i32> = if ids.is_empty() {
+        Vec::new()
+    } else {
+        boards::get_work_items_details(
+            &boards::create_client()?,
+            &creds.organization,
+            project,
+            &ids,
+            &fields,
+        )
+        .await
+        .iter()
+        .filter(|wi| sprint::field_str(wi, "System.WorkItemType") == "Task")
+        .map(|wi| wi.id)
+        .collect()
+    };
+    if task_ids.is_empty() {
+        println!("No tasks planned in this iteration.");
+        return Ok(());
+    }
+
+    let closed_states: Vec<String> = states::get_type_states(project, "Task")
+        .await?
+        .states
+        .into_iter()
+        .filter(|(_, category)| category == "Completed" || category == "Removed")
+        .map(|(name, _)| name)
+        .collect();
+
+    println!("Replaying the history of {} tasks...", task_ids.len());
+    let histories: Vec<Vec<Snapshot>> = history::get_all_updates(project, &task_ids)
+        .await?
+        .iter()
+        .map(|updates| snapshots(updates))
+        .collect();
+
+    let actual: Vec<Option<f64>> = days
+        .iter()
+        .map(|day| {
+            (*day <= today).then(|| {
+                histories
+                    .iter()
+                    .map(|h| remaining_on(h, *day, &iteration_path, &closed_states))
+                    .sum()
+            })
+        })
+        .collect();
+    let total = actual.first().copied().flatten().unwrap_or(0.0);
+    let steps = (days.len() - 1).max(1) as f64;
+    let ideal: Vec<f64> = (0..days.len())
+        .map(|i| total * (1.0 - i as f64 / steps))
+        .collect();
+
+    println!();
+    println!(
+        "🔥 Burndown: {} ({}) {start} → {finish}",
+        iteration.name.as_deref().unwrap_or("Unnamed").bold(),
+        team
+    );
+    println!();
+    for line in render_chart(&actual, &ideal, CHART_HEIGHT) {
+        println!("{line}");
+    }
+    let labels: String = days.iter().map(|d| format!(" {:02}", d.day())).collect();
+    println!("{}{labels}", " ".repeat(11));
+    println!();
+    println!("██ remaining work   ·· ideal trend");
+    if let Some(remaining) = actual.iter().rev().flatten().next() {
+        println!("⏳ Remaining: {remaining:.1} h of {total:.1} h");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use serde_json::json;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_remaining_on_replays_history() {
+        let updates: Vec<WorkItemUpdate> = serde_json::from_value(json!([
+            { "url": "", "fields": {
+                "System.ChangedDate": { "newValue": "2026-10-05T12:00:00Z" },
+                "System.State": { "newValue": "To Do" },
+                "System.IterationPath": { "newValue": "Shop\\Sprint 1" },
+                "Microsoft.VSTS.Scheduling.RemainingWork": { "newValue": 8.0 } } },
+            { "url": "", "fields": {
+                "System.ChangedDate": { "newValue": "2026-10-07T12:00:00Z" },
+                "Microsoft.VSTS.Scheduling.RemainingWork": { "oldValue": 8.0, "newValue": 3.0 } } },
+            { "url": "", "fields": {
+                "System.ChangedDate": { "newValue": "2026-10-09T12:00:00Z" },
+                "System.State": { "oldValue": "To Do", "newValue": "Done" } } }
+        ]))
+        .unwrap();
+        let history = snapshots(&updates);
+        let closed = vec!["Done".to_string()];
+        let remaining = |day| remaining_on(&history, day, "Shop\\Sprint 1", &closed);
+
+        assert_eq!(remaining(date(2026, 10, 4)), 0.0);
+        assert_eq!(remaining(date(2026, 10, 6)), 8.0);
+        assert_eq!(remaining(date(2026, 10, 8)), 3.0);
+        assert_eq!(remaining(date(2026, 10, 10)), 0.0);
+        assert_eq!(
+            remaining_on(&history, date(2026, 10, 6), "Shop\\Sprint 2", &closed),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_render_chart() {
+        let lines = render_chart(&[Some(4.0), Some(4.0), None], &[4.0, 3.0, 2.0], 2);
+        assert_eq!(
+            lines,
+            vec![
+                "    4.0 h │ ██ ██",
+                "          │ ██ ██ ··",
+                "    0.0 h └─────────",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_end_of_day_utc_is_next_local_midnight() {
+        let asof = end_of_day_utc(date(2026, 10, 5)).unwrap();
+        let local = DateTime::parse_from_rfc3339(&asof)
+            .unwrap()
+            .with_timezone(&Local);
+        assert_eq!(local.date_naive(), date(2026, 10, 6));
+        assert_eq!(local.time(), NaiveTime::MIN);
+    }
+}
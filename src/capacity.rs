@@ -0,0 +1,243 @@
+use crate::auth::get_credentials;
+use crate::boards;
+use crate::sprint::{self, MemberCapacity};
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItem;
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use std::collections::BTreeMap;
+
+/// Fields needed to sum up the remaining work of Tasks
+const TASK_FIELDS: [&str; 4] = [
+    "System.WorkItemType",
+    "System.AssignedTo",
+    "Microsoft.VSTS.Scheduling.RemainingWork",
+    "Microsoft.VSTS.Common.Activity",
+];
+
+/// Activity name used for capacity and Tasks without an activity
+const NO_ACTIVITY: &str = "Unassigned";
+
+/// Remaining work of the Tasks, by person and activity
+pub fn remaining_by_person_and_activity(tasks: &[WorkItem]) -> BTreeMap<(String, String), f64> {
+    let mut remaining = BTreeMap::new();
+    for task in tasks {
+        let activity = sprint::field_str(task, "Microsoft.VSTS.Common.Activity");
+        let activity = if activity.is_empty() {
+            NO_ACTIVITY
+        } else {
+            activity
+        };
+        *remaining
+            .entry((sprint::assignee(task), activity.to_string()))
+            .or_default() += sprint::remaining_work(task);
+    }
+    remaining
+}
+
+fn format_ranges(ranges: &[(NaiveDate, NaiveDate)]) -> String {
+    ranges
+        .iter()
+        .map(|(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{start} → {end}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn display_days_off(members: &[MemberCapacity], team_days_off: &[(NaiveDate, NaiveDate)]) {
+    println!();
+    println!("{}", "🏖️  Days off".bold());
+    if team_days_off.is_empty() && members.iter().all(|m| m.days_off.is_empty()) {
+        println!("No days off planned.");
+        return;
+    }
+    if !team_days_off.is_empty() {
+        println!("{:<30} {}", "Team", format_ranges(team_days_off));
+    }
+    for member in members.iter().filter(|m| !m.days_off.is_empty()) {
+        println!("{:<30} {}", member.name, format_ranges(&member.days_off));
+    }
+}
+
+fn status(remaining: f64, capacity: f64) -> String {
+    if remaining > capacity {
+        format!("over by {:.1} h", remaining - capacity)
+            .red()
+            .to_string()
+    } else {
+        "ok".green().to_string()
+    }
+}
+
+fn display_capacity_table(
+    members: &[MemberCapacity],
+    mut remaining: BTreeMap<(String, String), f64>,
+    from: Option<NaiveDate>,
+    finish: Option<NaiveDate>,
+    team_days_off: &[(NaiveDate, NaiveDate)],
+) {
+    println!();
+    println!("{}", "👥 Capacity per person and activity".bold());
+    println!(
+        "{:<30} {:<15} {:>8} {:>5} {:>12} {:>12}  Status",
+        "Person", "Activity", "Per day", "Days", "Capacity", "Remaining"
+    );
+    println!("{}", "-".repeat(100));
+
+    let mut by_activity: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+    for member in members {
+        let days = match (from, finish) {
+            (Some(from), Some(finish)) if from <= finish => {
+                let mut days_off = team_days_off.to_vec();
+                days_off.extend(member.days_off.iter().copied());
+                sprint::working_days(from, finish, &days_off)
+            }
+            _ => 0,
+        };
+        for (activity, per_day) in &member.activities {
+            let capacity = per_day * days as f64;
+            let work = remaining
+                .remove(&(member.name.clone(), activity.clone()))
+                .unwrap_or(0.0);
+            let totals = by_activity.entry(activity.clone()).or_default();
+            totals.0 += capacity;
+            totals.1 += work;
+            println!(
+                "{:<30} {:<15} {:>6.1} h {:>5} {:>10.1} h {:>10.1} h  {}",
+                member.name,
+                activity,
+                per_day,
+                days,
+                capacity,
+                work,
+                status(work, capacity)
+            );
+        }
+    }
+
+    // Remaining work of people or activities without capacity set
+    for ((person, activity), work) in remaining {
+        if work > 0.0 {
+            by_activity.entry(activity.clone()).or_default().1 += work;
+            println!(
+                "{:<30} {:<15} {:>8} {:>5} {:>12} {:>10.1} h  {}",
+                person,
+                activity,
+                "-",
+                "-",
+                "-",
+                work,
+                "no capacity set".yellow()
+            );
+        }
+    }
+
+    println!();
+    println!("{}", "📊 Totals per activity".bold());
+    println!(
+        "{:<15} {:>12} {:>12}  Status",
+        "Activity", "Capacity", "Remaining"
+    );
+    println!("{}", "-".repeat(60));
+    for (activity, (capacity, work)) in &by_activity {
+        println!(
+            "{:<15} {:>10.1} h {:>10.1} h  {}",
+            activity,
+            capacity,
+            work,
+            status(*work, *capacity)
+        );
+    }
+    let capacity: f64 = by_activity.values().map(|(c, _)| c).sum();
+    let work: f64 = by_activity.values().map(|(_, w)| w).sum();
+    println!(
+        "{:<15} {:>10.1} h {:>10.1} h  {}",
+        "Total".bold(),
+        capacity,
+        work,
+        status(work, capacity)
+    );
+}
+
+pub async fn show_capacity(project: &str, team: &str, iteration: &str) -> Result<()> {
+    let iteration = sprint::resolve_iteration(project, team, iteration).await?;
+    let iteration_id = iteration
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Iteration ID is missing"))?;
+
+    let (members, team_days_off) =
+        sprint::get_member_capacities(project, team, &iteration_id).await?;
+    let today = Local::now().date_naive();
+    let from = sprint::display_sprint_header(team, &iteration, today, &team_days_off);
+    let finish = sprint::iteration_dates(&iteration).map(|(_, finish)| finish);
+
+    let ids = sprint::get_iteration_work_item_ids(project, team, &iteration_id).await?;
+    let tasks: Vec<WorkItem> = if ids.is_empty() {
+        Vec::new()
+    } else {
+        let creds = get_credentials()?;
+        let fields = TASK_FIELDS.map(String::from);
+        boards::get_work_items_details(
+            &boards::create_client()?,
+            &creds.organization,
+            project,
+            &ids,
+            &fields,
+        )
+        .await
+        .into_iter()
+        .filter(|wi| sprint::field_str(wi, "System.WorkItemType") == "Task")
+        .collect()
+    };
+
+    display_days_off(&members, &team_days_off);
+    display_capacity_table(
+        &members,
+        remaining_by_person_and_activity(&tasks),
+        from,
+        finish,
+        &team_days_off,
+    );
+
+    println!("\nTotal: {} tasks", tasks.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_remaining_by_person_and_activity() {
+        let tasks: Vec<WorkItem> = serde_json::from_value(json!([
+            { "url": "", "id": 1, "rev": 1, "fields": {
+                "System.AssignedTo": { "displayName": "Jane Doe" },
+                "Microsoft.VSTS.Common.Activity": "Development",
+                "Microsoft.VSTS.Scheduling.RemainingWork": 5.0 } },
+            { "url": "", "id": 2, "rev": 1, "fields": {
+                "System.AssignedTo": { "displayName": "Jane Doe" },
+                "Microsoft.VSTS.Common.Activity": "Development",
+                "Microsoft.VSTS.Scheduling.RemainingWork": 3.0 } },
+            { "url": "", "id": 3, "rev": 1, "fields": {
+                "Microsoft.VSTS.Scheduling.RemainingWork": 2.0 } }
+        ]))
+        .unwrap();
+
+        let remaining = remaining_by_person_and_activity(&tasks);
+        assert_eq!(
+            remaining[&("Jane Doe".to_string(), "Development".to_string())],
+            8.0
+        );
+        assert_eq!(
+            remaining[&("Unassigned".to_string(), NO_ACTIVITY.to_string())],
+            2.0
+        );
+    }
+}
@@ -0,0 +1,637 @@
+use crate::auth::get_credentials;
+use crate::boards::{self, create_client, sanitize_wiql_value};
+use crate::project::get_project_or_default;
+use crate::query;
+use crate::sprint::{self, team_or_default};
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::{
+    WorkItemClassificationNode, WorkItemTrackingResource, WorkItemTrackingResourceReference,
+};
+use azure_devops_rust_api::work::models::TeamSettingsIteration;
+use chrono::NaiveDate;
+use clap::Subcommand;
+use colored::Colorize;
+use dialoguer::Confirm;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Depth of the classification node trees that is loaded
+const TREE_DEPTH: i32 = 20;
+
+#[derive(Subcommand, Clone)]
+pub enum AreaSubCommands {
+    /// List the area paths of a project
+    List {
+        /// Show an indented tree with the number of work items per area
+        #[clap(long)]
+        tree: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Create an area path, e.g. 'Team A/Payments'
+    Create {
+        /// Path of the new area, relative to the project's root area
+        #[clap(long)]
+        path: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Rename an area path
+    Rename {
+        /// Path of the area to rename
+        #[clap(long)]
+        path: String,
+        /// New name of the area
+        #[clap(long)]
+        name: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Delete an area path
+    Delete {
+        /// Path of the area to delete
+        #[clap(long)]
+        path: String,
+        /// Area to move the work items of the deleted area to (defaults to the root area)
+        #[clap(long)]
+        reclassify_to: Option<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        /// Skip confirmation prompt and proceed directly
+        #[clap(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum IterationSubCommands {
+    /// List the iteration paths of a project with their dates
+    List {
+        /// Show an indented tree with the number of work items per iteration
+        #[clap(long)]
+        tree: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Create an iteration path, e.g. '2026 Q4/Sprint 1'
+    Create {
+        /// Path of the new iteration, relative to the project's root iteration
+        #[clap(long)]
+        path: String,
+        /// Start date (YYYY-MM-DD)
+        #[clap(long, requires = "finish")]
+        start: Option<String>,
+        /// Finish date (YYYY-MM-DD)
+        #[clap(long, requires = "start")]
+        finish: Option<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Add an iteration to a team's sprints
+    AssignTeam {
+        /// Path of the iteration
+        #[clap(long)]
+        path: String,
+        /// Team name (defaults to the project's default team)
+        #[clap(long)]
+        team: Option<String>,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+}
+
+/// The two classification node trees of a project
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StructureGroup {
+    Areas,
+    Iterations,
+}
+
+impl StructureGroup {
+    fn api_name(self) -> &'static str {
+        match self {
+            StructureGroup::Areas => "areas",
+            StructureGroup::Iterations => "iterations",
+        }
+    }
+
+    fn field(self) -> &'static str {
+        match self {
+            StructureGroup::Areas => "System.AreaPath",
+            StructureGroup::Iterations => "System.IterationPath",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            StructureGroup::Areas => "Area",
+            StructureGroup::Iterations => "Iteration",
+        }
+    }
+}
+
+pub async fn handle_area_command(subcommand: &AreaSubCommands) -> Result<()> {
+    let group = StructureGroup::Areas;
+    let result = match subcommand {
+        AreaSubCommands::List { tree, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            list_nodes(&project_name, group, *tree).await
+        }
+        AreaSubCommands::Create { path, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            create_node(&project_name, group, path, None).await
+        }
+        AreaSubCommands::Rename {
+            path,
+            name,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            rename_node(&project_name, group, path, name).await
+        }
+        AreaSubCommands::Delete {
+            path,
+            reclassify_to,
+            project,
+            yes,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            if !*yes {
+                let prompt_message = format!(
+                    "Are you sure you want to delete area '{path}'? Its work items are moved to {}",
+                    reclassify_to.as_deref().unwrap_or("the root area")
+                );
+                if !Confirm::new()
+                    .with_prompt(prompt_message)
+                    .default(false)
+                    .interact()?
+                {
+                    println!("Delete operation cancelled.");
+                    return Ok(());
+                }
+            }
+            delete_node(&project_name, group, path, reclassify_to.as_deref()).await
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("❌ Failed to manage area paths: {e}");
+        return Err(e);
+    }
+    Ok(())
+}
+
+pub async fn handle_iteration_command(subcommand: &IterationSubCommands) -> Result<()> {
+    let group = StructureGroup::Iterations;
+    let result = match subcommand {
+        IterationSubCommands::List { tree, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            list_nodes(&project_name, group, *tree).await
+        }
+        IterationSubCommands::Create {
+            path,
+            start,
+            finish,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let dates = match (start, finish) {
+                (Some(start), Some(finish)) => Some((parse_date(start)?, parse_date(finish)?)),
+                _ => None,
+            };
+            if let Some((start, finish)) = dates {
+                if finish < start {
+                    return Err(anyhow!("The finish date must not be before the start date"));
+                }
+            }
+            create_node(&project_name, group, path, dates).await
+        }
+        IterationSubCommands::AssignTeam {
+            path,
+            team,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let team_name = team_or_default(&project_name, team.as_deref()).await?;
+            assign_team(&project_name, &team_name, path).await
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("❌ Failed to manage iteration paths: {e}");
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| anyhow!("Invalid date '{}', expected YYYY-MM-DD", date))
+}
+
+/// Turns a user-supplied path into the node path used by the API: '/' separated
+/// and relative to the root node. A leading project name is accepted and dropped.
+pub fn node_path(project: &str, path: &str) -> String {
+    let mut segments: Vec<&str> = path
+        .split(['\\', '/'])
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if segments
+        .first()
+        .is_some_and(|first| first.eq_ignore_ascii_case(project))
+    {
+        segments.remove(0);
+    }
+    segments.join("/")
+}
+
+/// Converts the path of a node (e.g. '\Shop\Area\Team A') into the path stored
+/// on work items (e.g. 'Shop\Team A')
+pub fn work_item_path(node_path: &str) -> String {
+    let segments: Vec<&str> = node_path.split('\\').filter(|s| !s.is_empty()).collect();
+    match segments.split_first() {
+        Some((project, rest)) => std::iter::once(*project)
+            .chain(rest.iter().skip(1).copied())
+            .collect::<Vec<_>>()
+            .join("\\"),
+        None => String::new(),
+    }
+}
+
+/// Start and finish dates of an iteration node, as YYYY-MM-DD
+fn node_dates(node: &WorkItemClassificationNode) -> Option<(String, String)> {
+    let attributes = node.attributes.as_ref()?;
+    let date = |key: &str| -> Option<String> {
+        attributes
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|d| d.chars().take(10).collect())
+    };
+    Some((date("startDate")?, date("finishDate")?))
+}
+
+async fn get_node(
+    project: &str,
+    group: StructureGroup,
+    path: &str,
+    depth: Option<i32>,
+) -> Result<WorkItemClassificationNode> {
+    let creds = get_credentials()?;
+    let path = node_path(project, path);
+    let mut request = create_client()?.classification_nodes_client().get(
+        creds.organization,
+        project,
+        group.api_name(),
+        path.clone(),
+    );
+    if let Some(depth) = depth {
+        request = request.depth(depth);
+    }
+    request.await.map_err(|e| {
+        if path.is_empty() {
+            anyhow!("{}", e)
+        } else {
+            anyhow!("{} '{}' not found: {}", group.label(), path, e)
+        }
+    })
+}
+
+fn new_node(name: &str) -> WorkItemClassificationNode {
+    let mut node = WorkItemClassificationNode::new(WorkItemTrackingResource::new(
+        WorkItemTrackingResourceReference::new(String::new()),
+    ));
+    node.name = Some(name.to_string());
+    node
+}
+
+/// A node of the tree, flattened in display order with its depth
+struct TreeLine<'a> {
+    depth: usize,
+    node: &'a WorkItemClassificationNode,
+}
+
+fn flatten_tree(node: &WorkItemClassificationNode) -> Vec<TreeLine<'_>> {
+    fn visit<'a>(
+        node: &'a WorkItemClassificationNode,
+        depth: usize,
+        lines: &mut Vec<TreeLine<'a>>,
+    ) {
+        lines.push(TreeLine { depth, node });
+        for child in &node.children {
+            visit(child, depth + 1, lines);
+        }
+    }
+    let mut lines = Vec::new();
+    visit(node, 0, &mut lines);
+    lines
+}
+
+/// Counts how many of `item_paths` sit directly on each of `paths`, ignoring case
+fn count_by_path<'a>(paths: &[String], item_paths: impl Iterator<Item = &'a str>) -> Vec<usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for path in item_paths {
+        *counts.entry(path.to_lowercase()).or_default() += 1;
+    }
+    paths
+        .iter()
+        .map(|path| counts.get(&path.to_lowercase()).copied().unwrap_or(0))
+        .collect()
+}
+
+/// Counts the work items of every line that sit directly on its node.
+///
+/// A single UNDER query loads the whole tree, and the items are grouped by their path.
+async fn count_work_items(
+    project: &str,
+    group: StructureGroup,
+    lines: &[TreeLine<'_>],
+) -> Result<Vec<usize>> {
+    let paths: Vec<String> = lines
+        .iter()
+        .map(|line| work_item_path(line.node.path.as_deref().unwrap_or_default()))
+        .collect();
+    let Some(root) = paths.first() else {
+        return Ok(Vec::new());
+    };
+
+    let wiql = format!(
+        "SELECT [System.Id], [{field}] FROM WorkItems WHERE [System.TeamProject] = '{}' AND [{field}] UNDER '{}'",
+        sanitize_wiql_value(project),
+        sanitize_wiql_value(root),
+        field = group.field()
+    );
+    let result = query::execute_query(project, Some(&wiql), None).await?;
+    let ids = query::result_ids(&result);
+    if ids.is_empty() {
+        return Ok(vec![0; paths.len()]);
+    }
+
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let work_items = boards::get_work_items_details(
+        &client,
+        &creds.organization,
+        project,
+        &ids,
+        &[group.field().to_string()],
+    )
+    .await;
+    let item_paths = work_items
+        .iter()
+        .filter_map(|wi| wi.fields.get(group.field()).and_then(|v| v.as_str()));
+    Ok(count_by_path(&paths, item_paths))
+}
+
+/// Adds the counts of each line's descendants to its own count
+fn subtree_totals(depths: &[usize], counts: &[usize]) -> Vec<usize> {
+    (0..depths.len())
+        .map(|i| {
+            counts[i]
+                + depths[i + 1..]
+                    .iter()
+                    .zip(&counts[i + 1..])
+                    .take_while(|(depth, _)| **depth > depths[i])
+                    .map(|(_, count)| count)
+                    .sum::<usize>()
+        })
+        .collect()
+}
+
+async fn list_nodes(project: &str, group: StructureGroup, tree: bool) -> Result<()> {
+    let root = get_node(project, group, "", Some(TREE_DEPTH)).await?;
+    let lines = flatten_tree(&root);
+
+    if !tree {
+        println!(
+            "{:<8} {:<60} {:<12} {:<12}",
+            "ID", "Path", "Start", "Finish"
+        );
+        println!("{}", "-".repeat(95));
+        for line in &lines {
+            let (start, finish) = node_dates(line.node).unwrap_or_default();
+            println!(
+                "{:<8} {:<60} {:<12} {:<12}",
+                line.node.id.map(|id| id.to_string()).unwrap_or_default(),
+                work_item_path(line.node.path.as_deref().unwrap_or_default()),
+                start,
+                finish
+            );
+        }
+        println!();
+        println!(
+            "Total: {} {} paths",
+            lines.len(),
+            group.label().to_lowercase()
+        );
+        return Ok(());
+    }
+
+    println!("Counting work items for {} nodes...", lines.len());
+    let counts = count_work_items(project, group, &lines).await?;
+    let depths: Vec<usize> = lines.iter().map(|l| l.depth).collect();
+    let totals = subtree_totals(&depths, &counts);
+
+    println!();
+    for (i, line) in lines.iter().enumerate() {
+        let name = line.node.name.as_deref().unwrap_or_default();
+        let label = if i == 0 { name.bold() } else { name.normal() };
+        let mut details = format!("{} items", counts[i]);
+        if totals[i] != counts[i] {
+            details.push_str(&format!(", {} in total", totals[i]));
+        }
+        if let Some((start, finish)) = node_dates(line.node) {
+            details.push_str(&format!(", {start} → {finish}"));
+        }
+        println!(
+            "{}{} {}",
+            "  ".repeat(line.depth),
+            label,
+            format!("({details})").dimmed()
+        );
+    }
+    Ok(())
+}
+
+async fn create_node(
+    project: &str,
+    group: StructureGroup,
+    path: &str,
+    dates: Option<(NaiveDate, NaiveDate)>,
+) -> Result<()> {
+    let path = node_path(project, path);
+    let (parent, name) = match path.rsplit_once('/') {
+        Some((parent, name)) => (parent.to_string(), name.to_string()),
+        None => (String::new(), path.clone()),
+    };
+    if name.is_empty() {
+        return Err(anyhow!("The path of the new {} is empty", group.api_name()));
+    }
+
+    let mut node = new_node(&name);
+    if let Some((start, finish)) = dates {
+        node.attributes = Some(json!({
+            "startDate": format!("{start}T00:00:00Z"),
+            "finishDate": format!("{finish}T00:00:00Z"),
+        }));
+    }
+
+    let creds = get_credentials()?;
+    let created = create_client()?
+        .classification_nodes_client()
+        .create_or_update(creds.organization, node, project, group.api_name(), parent)
+        .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Created {} '{}'",
+            group.label().to_lowercase(),
+            work_item_path(created.path.as_deref().unwrap_or_default())
+        )
+        .green()
+    );
+    Ok(())
+}
+
+async fn rename_node(project: &str, group: StructureGroup, path: &str, name: &str) -> Result<()> {
+    let path = node_path(project, path);
+    if path.is_empty() {
+        return Err(anyhow!("The root {} cannot be renamed", group.api_name()));
+    }
+
+    let creds = get_credentials()?;
+    let renamed = create_client()?
+        .classification_nodes_client()
+        .update(
+            creds.organization,
+            new_node(name),
+            project,
+            group.api_name(),
+            path.clone(),
+        )
+        .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Renamed '{}' to '{}'",
+            path,
+            work_item_path(renamed.path.as_deref().unwrap_or_default())
+        )
+        .green()
+    );
+    Ok(())
+}
+
+async fn delete_node(
+    project: &str,
+    group: StructureGroup,
+    path: &str,
+    reclassify_to: Option<&str>,
+) -> Result<()> {
+    let path = node_path(project, path);
+    if path.is_empty() {
+        return Err(anyhow!("The root {} cannot be deleted", group.api_name()));
+    }
+
+    // Work items on the deleted node have to go somewhere, the root node by default
+    let target = get_node(project, group, reclassify_to.unwrap_or_default(), None).await?;
+    let target_id = target
+        .id
+        .ok_or_else(|| anyhow!("{} has no ID", group.label()))?;
+
+    let creds = get_credentials()?;
+    create_client()?
+        .classification_nodes_client()
+        .delete(creds.organization, project, group.api_name(), path.clone())
+        .reclassify_id(target_id)
+        .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Deleted '{}', work items moved to '{}'",
+            path,
+            work_item_path(target.path.as_deref().unwrap_or_default())
+        )
+        .green()
+    );
+    Ok(())
+}
+
+async fn assign_team(project: &str, team: &str, path: &str) -> Result<()> {
+    let node = get_node(project, StructureGroup::Iterations, path, None).await?;
+    let mut iteration = TeamSettingsIteration::new();
+    iteration.id = Some(
+        node.identifier
+            .clone()
+            .ok_or_else(|| anyhow!("Iteration '{}' has no identifier", path))?,
+    );
+
+    let creds = get_credentials()?;
+    sprint::create_client()?
+        .iterations_client()
+        .post_team_iteration(creds.organization, iteration, project, team)
+        .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Added '{}' to the iterations of team '{}'",
+            work_item_path(node.path.as_deref().unwrap_or_default()),
+            team
+        )
+        .green()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_and_work_item_paths() {
+        assert_eq!(
+            node_path("Shop", "Shop\\Team A\\Payments"),
+            "Team A/Payments"
+        );
+        assert_eq!(node_path("Shop", "/Team A/Payments/"), "Team A/Payments");
+        assert_eq!(node_path("Shop", "Shop"), "");
+
+        assert_eq!(work_item_path("\\Shop\\Area\\Team A"), "Shop\\Team A");
+        assert_eq!(work_item_path("\\Shop\\Iteration"), "Shop");
+    }
+
+    #[test]
+    fn test_count_by_path() {
+        let paths = vec![
+            "Shop".to_string(),
+            "Shop\\Web".to_string(),
+            "Shop\\Web\\Checkout".to_string(),
+        ];
+        let items = [
+            "Shop\\Web",
+            "shop\\web",
+            "Shop\\Web\\Checkout",
+            "Shop\\Other",
+        ];
+        assert_eq!(count_by_path(&paths, items.into_iter()), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_subtree_totals() {
+        // root, a, a/b, c
+        let depths = [0, 1, 2, 1];
+        let counts = [1, 2, 3, 4];
+        assert_eq!(subtree_totals(&depths, &counts), vec![10, 5, 3, 4]);
+    }
+}
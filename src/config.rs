@@ -16,3 +16,15 @@ pub fn get_config_dir() -> anyhow::Result<PathBuf> {
 pub fn get_home_dir() -> Option<PathBuf> {
     dirs::home_dir()
 }
+
+/// Server that hosts the organization, e.g. 'https://dev.azure.com' or an
+/// Azure DevOps Server collection URL, read from `server_url` in config.json
+pub fn get_server_url() -> Option<String> {
+    let config_file = get_config_dir().ok()?.join("config.json");
+    let config_content = fs::read_to_string(config_file).ok()?;
+    let config: serde_json::Value = serde_json::from_str(&config_content).ok()?;
+    config["server_url"]
+        .as_str()
+        .map(|url| url.trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+}
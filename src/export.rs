@@ -0,0 +1,271 @@
+use crate::auth::get_credentials;
+use crate::boards::{create_client, get_work_items_details};
+use crate::history;
+use crate::query::{self, format_field_value};
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::{WorkItem, WorkItemUpdate};
+use clap::ValueEnum;
+use colored::Colorize;
+use serde_json::{json, Value};
+
+/// File formats supported by `work-item export`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Md,
+}
+
+impl ExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Md => "md",
+        }
+    }
+}
+
+/// Column holding the state transitions added by `--with-history`
+const HISTORY_COLUMN: &str = "StateHistory";
+
+/// One exported work item: its ID and the value of each column
+struct ExportRow {
+    id: i32,
+    values: Vec<Value>,
+}
+
+fn column_name(field: &str) -> &str {
+    if field == HISTORY_COLUMN {
+        field
+    } else {
+        field.rsplit('.').next().unwrap_or(field)
+    }
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn markdown_escape(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Renders a column value as text, joining state transitions into one cell
+fn text_value(value: &Value) -> String {
+    match value {
+        Value::Array(changes) => changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "{}@{}",
+                    change["state"].as_str().unwrap_or_default(),
+                    change["date"].as_str().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; "),
+        other => format_field_value(other),
+    }
+}
+
+fn to_csv(columns: &[String], rows: &[ExportRow]) -> String {
+    let mut output = std::iter::once("ID")
+        .chain(columns.iter().map(|c| column_name(c)))
+        .map(csv_escape)
+        .collect::<Vec<_>>()
+        .join(",");
+    output.push('\n');
+
+    for row in rows {
+        let line = std::iter::once(row.id.to_string())
+            .chain(row.values.iter().map(text_value))
+            .map(|v| csv_escape(&v))
+            .collect::<Vec<_>>()
+            .join(",");
+        output.push_str(&line);
+        output.push('\n');
+    }
+    output
+}
+
+fn to_markdown(columns: &[String], rows: &[ExportRow]) -> String {
+    let header: Vec<&str> = std::iter::once("ID")
+        .chain(columns.iter().map(|c| column_name(c)))
+        .collect();
+    let mut output = format!("| {} |\n", header.join(" | "));
+    output.push_str(&format!("|{}\n", "---|".repeat(header.len())));
+
+    for row in rows {
+        let cells: Vec<String> = std::iter::once(row.id.to_string())
+            .chain(row.values.iter().map(|v| markdown_escape(&text_value(v))))
+            .collect();
+        output.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    output
+}
+
+fn to_json(columns: &[String], rows: &[ExportRow]) -> Result<String> {
+    let items: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let mut item = serde_json::Map::new();
+            item.insert("id".to_string(), json!(row.id));
+            for (column, value) in columns.iter().zip(&row.values) {
+                item.insert(column.clone(), value.clone());
+            }
+            Value::Object(item)
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&items)?)
+}
+
+fn state_history(updates: &[WorkItemUpdate]) -> Value {
+    Value::Array(
+        history::state_changes(updates)
+            .into_iter()
+            .map(|change| json!({ "state": change.to, "date": change.date }))
+            .collect(),
+    )
+}
+
+async fn build_rows(
+    project: &str,
+    work_items: &[WorkItem],
+    fields: &[String],
+    with_history: bool,
+) -> Vec<ExportRow> {
+    let histories = if with_history {
+        let ids: Vec<i32> = work_items.iter().map(|wi| wi.id).collect();
+        history::get_each_updates(project, &ids).await
+    } else {
+        Vec::new()
+    };
+
+    let mut rows = Vec::with_capacity(work_items.len());
+    for (index, work_item) in work_items.iter().enumerate() {
+        let mut values: Vec<Value> = fields
+            .iter()
+            .map(|field| work_item.fields.get(field).cloned().unwrap_or(Value::Null))
+            .collect();
+        // A failed history is marked on its row instead of failing the whole export
+        match histories.get(index) {
+            Some(Ok(updates)) => values.push(state_history(updates)),
+            Some(Err(e)) => {
+                eprintln!(
+                    "⚠️  Failed to get the history of work item {}: {e}",
+                    work_item.id
+                );
+                values.push(json!(format!("(history unavailable: {e})")));
+            }
+            None => {}
+        }
+        rows.push(ExportRow {
+            id: work_item.id,
+            values,
+        });
+    }
+    rows
+}
+
+pub async fn export_work_items(
+    project: &str,
+    wiql: &str,
+    format: ExportFormat,
+    fields: &[String],
+    output: Option<&str>,
+    with_relations: bool,
+    with_history: bool,
+) -> Result<()> {
+    let result = query::execute_query(project, Some(wiql), None).await?;
+    let ids = query::result_ids(&result);
+    let mut fields = query::resolve_fields(fields, &result);
+    if with_relations && !fields.iter().any(|f| f == "System.Parent") {
+        fields.push("System.Parent".to_string());
+    }
+
+    let work_items = if ids.is_empty() {
+        Vec::new()
+    } else {
+        let creds = get_credentials()?;
+        let client = create_client()?;
+        get_work_items_details(&client, &creds.organization, project, &ids, &fields).await
+    };
+
+    let rows = build_rows(project, &work_items, &fields, with_history).await;
+    let mut columns = fields;
+    if with_history {
+        columns.push(HISTORY_COLUMN.to_string());
+    }
+
+    let content = match format {
+        ExportFormat::Csv => to_csv(&columns, &rows),
+        ExportFormat::Json => to_json(&columns, &rows)?,
+        ExportFormat::Md => to_markdown(&columns, &rows),
+    };
+
+    let path = output
+        .map(|o| o.to_string())
+        .unwrap_or_else(|| format!("work-items.{}", format.extension()));
+    tokio::fs::write(&path, content)
+        .await
+        .map_err(|e| anyhow!("Failed to write '{}': {}", path, e))?;
+
+    println!(
+        "{}",
+        format!("✅ Exported {} work items to {}", rows.len(), path).green()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> (Vec<String>, Vec<ExportRow>) {
+        let columns = vec![
+            "System.Title".to_string(),
+            "System.AssignedTo".to_string(),
+            HISTORY_COLUMN.to_string(),
+        ];
+        let rows = vec![ExportRow {
+            id: 7,
+            values: vec![
+                json!("Fix \"login\", again | soon"),
+                json!({ "displayName": "Jane Doe" }),
+                json!([
+                    { "state": "New", "date": "2026-01-05T09:00:00Z" },
+                    { "state": "Active", "date": "2026-01-07T10:30:00Z" }
+                ]),
+            ],
+        }];
+        (columns, rows)
+    }
+
+    #[test]
+    fn test_to_csv_escapes_values() {
+        let (columns, rows) = rows();
+        assert_eq!(
+            to_csv(&columns, &rows),
+            "ID,Title,AssignedTo,StateHistory\n\
+             7,\"Fix \"\"login\"\", again | soon\",Jane Doe,New@2026-01-05T09:00:00Z; Active@2026-01-07T10:30:00Z\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_escapes_pipes() {
+        let (columns, rows) = rows();
+        let markdown = to_markdown(&columns, &rows);
+        assert!(
+            markdown.starts_with("| ID | Title | AssignedTo | StateHistory |\n|---|---|---|---|\n")
+        );
+        assert!(markdown.contains("| 7 | Fix \"login\", again \\| soon | Jane Doe |"));
+    }
+}
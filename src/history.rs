@@ -0,0 +1,405 @@
+use crate::auth::get_credentials;
+use crate::boards::create_client;
+use crate::query::format_field_value;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::{WorkItemRelation, WorkItemUpdate};
+use colored::Colorize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Number of updates requested per page
+const UPDATES_PAGE_SIZE: i32 = 200;
+
+/// Number of work items whose history is fetched at the same time
+const HISTORY_CONCURRENCY: usize = 8;
+
+/// Maximum length of a field value in the history output
+const VALUE_WIDTH: usize = 60;
+
+/// Bookkeeping fields that change with every revision
+const NOISE_FIELDS: [&str; 9] = [
+    "System.Rev",
+    "System.ChangedDate",
+    "System.ChangedBy",
+    "System.AuthorizedDate",
+    "System.AuthorizedAs",
+    "System.RevisedDate",
+    "System.Watermark",
+    "System.PersonId",
+    "System.CommentCount",
+];
+
+/// A change of a work item's state
+#[derive(Debug, PartialEq)]
+pub struct StateChange {
+    pub from: Option<String>,
+    pub to: String,
+    pub date: String,
+}
+
+/// Fetches every update (revision delta) of a work item, oldest first
+pub async fn get_updates(project: &str, id: i32) -> Result<Vec<WorkItemUpdate>> {
+    let creds = get_credentials()?;
+    let client = create_client()?;
+
+    let mut updates = Vec::new();
+    loop {
+        let page = client
+            .updates_client()
+            .list(&creds.organization, id, project)
+            .top(UPDATES_PAGE_SIZE)
+            .skip(updates.len() as i32)
+            .await?
+            .value;
+        let count = page.len();
+        updates.extend(page);
+        if count < UPDATES_PAGE_SIZE as usize {
+            break;
+        }
+    }
+
+    Ok(updates)
+}
+
+/// Fetches the updates of several work items, a few at a time, in the order of `ids`,
+/// with a separate result for each work item
+pub async fn get_each_updates(project: &str, ids: &[i32]) -> Vec<Result<Vec<WorkItemUpdate>>> {
+    let semaphore = Arc::new(Semaphore::new(HISTORY_CONCURRENCY));
+    let mut tasks = Vec::new();
+    for &id in ids {
+        let project = project.to_string();
+        let semaphore = semaphore.clone();
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire().await.unwrap();
+            get_updates(&project, id).await
+        }));
+    }
+
+    let mut all_updates = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        all_updates.push(task.await.map_err(anyhow::Error::from).and_then(|r| r));
+    }
+    all_updates
+}
+
+/// Fetches the updates of several work items, failing if any of them can't be fetched
+pub async fn get_all_updates(project: &str, ids: &[i32]) -> Result<Vec<Vec<WorkItemUpdate>>> {
+    get_each_updates(project, ids).await.into_iter().collect()
+}
+
+/// Returns the old and new value of a field changed by an update
+pub fn field_change<'a>(
+    update: &'a WorkItemUpdate,
+    field: &str,
+) -> Option<(Option<&'a serde_json::Value>, Option<&'a serde_json::Value>)> {
+    let change = update.fields.as_ref()?.get(field)?;
+    Some((change.get("oldValue"), change.get("newValue")))
+}
+
+/// Extracts the state transitions with the time each one happened
+pub fn state_changes(updates: &[WorkItemUpdate]) -> Vec<StateChange> {
+    updates
+        .iter()
+        .filter_map(|update| {
+            let (old, new) = field_change(update, "System.State")?;
+            let date = field_change(update, "System.ChangedDate")
+                .and_then(|(_, new)| new)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            Some(StateChange {
+                from: old.and_then(|v| v.as_str()).map(|s| s.to_string()),
+                to: new?.as_str()?.to_string(),
+                date: date.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A field whose value differs between two revisions
+#[derive(Debug, PartialEq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+}
+
+/// Compares the fields of two revisions, ignoring bookkeeping fields
+pub fn diff_fields(old: &Value, new: &Value) -> Vec<FieldDiff> {
+    let empty = serde_json::Map::new();
+    let old = old.as_object().unwrap_or(&empty);
+    let new = new.as_object().unwrap_or(&empty);
+
+    old.keys()
+        .chain(new.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|field| !NOISE_FIELDS.contains(&field.as_str()))
+        .filter(|field| old.get(*field) != new.get(*field))
+        .map(|field| FieldDiff {
+            field: field.clone(),
+            old: old.get(field).cloned(),
+            new: new.get(field).cloned(),
+        })
+        .collect()
+}
+
+/// Renders a value on a single line, shortened to fit the history output
+pub fn plain_value(value: Option<&Value>) -> String {
+    let text = value.map(format_field_value).unwrap_or_default();
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        "(empty)".to_string()
+    } else if text.chars().count() > VALUE_WIDTH {
+        format!(
+            "{}...",
+            text.chars().take(VALUE_WIDTH - 3).collect::<String>()
+        )
+    } else {
+        text
+    }
+}
+
+fn display_field_diff(field: &str, old: Option<&Value>, new: Option<&Value>) {
+    println!(
+        "    {}: {} → {}",
+        field.bold(),
+        plain_value(old).red(),
+        plain_value(new).green()
+    );
+}
+
+fn relation_label(relation: &WorkItemRelation) -> String {
+    let rel = relation
+        .link
+        .rel
+        .rsplit('.')
+        .next()
+        .unwrap_or(&relation.link.rel)
+        .to_string();
+    let target = relation
+        .link
+        .url
+        .rsplit('/')
+        .next()
+        .unwrap_or(&relation.link.url);
+    format!("{rel} {target}")
+}
+
+fn display_update(update: &WorkItemUpdate) {
+    let who = update
+        .revised_by
+        .as_ref()
+        .and_then(|r| r.graph_subject_base.display_name.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+    let when = field_change(update, "System.ChangedDate")
+        .and_then(|(_, new)| new)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    println!(
+        "{} {} {}",
+        format!("Rev {}", update.rev.unwrap_or_default())
+            .cyan()
+            .bold(),
+        who,
+        when.dimmed()
+    );
+
+    if let Some(fields) = update.fields.as_ref().and_then(|f| f.as_object()) {
+        for (field, change) in fields {
+            if NOISE_FIELDS.contains(&field.as_str()) {
+                continue;
+            }
+            display_field_diff(field, change.get("oldValue"), change.get("newValue"));
+        }
+    }
+
+    if let Some(relations) = &update.relations {
+        for relation in &relations.added {
+            println!("    {} {}", "+ link".green(), relation_label(relation));
+        }
+        for relation in &relations.removed {
+            println!("    {} {}", "- link".red(), relation_label(relation));
+        }
+    }
+    println!();
+}
+
+pub async fn show_history(project: &str, id: &str) -> Result<()> {
+    let id_int = id
+        .parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+
+    let updates = get_updates(project, id_int).await?;
+    println!();
+    println!("{}", format!("📚 History of work item {id}").bold());
+    println!("{}", "=".repeat(40));
+    for update in &updates {
+        display_update(update);
+    }
+    println!("Total: {} revisions", updates.len());
+    Ok(())
+}
+
+pub async fn show_diff(project: &str, id: &str, old_rev: i32, new_rev: i32) -> Result<()> {
+    let id_int = id
+        .parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let revisions = client.revisions_client();
+    let old = revisions
+        .get(&creds.organization, id_int, old_rev, project)
+        .await
+        .map_err(|e| anyhow!("Revision {} not found: {}", old_rev, e))?;
+    let new = revisions
+        .get(&creds.organization, id_int, new_rev, project)
+        .await
+        .map_err(|e| anyhow!("Revision {} not found: {}", new_rev, e))?;
+
+    println!();
+    println!(
+        "{}",
+        format!("🔍 Work item {id}: revision {old_rev} → {new_rev}").bold()
+    );
+    println!("{}", "=".repeat(40));
+
+    let diffs = diff_fields(&old.fields, &new.fields);
+    if diffs.is_empty() {
+        println!("No field changes between the two revisions.");
+    }
+    for diff in &diffs {
+        display_field_diff(&diff.field, diff.old.as_ref(), diff.new.as_ref());
+    }
+    Ok(())
+}
+
+/// Explains why an update guarded by `--expect-rev` was rejected.
+///
+/// Returns `None` when the work item is still at the expected revision, in
+/// which case the update failed for some other reason.
+pub async fn revision_conflict(
+    project: &str,
+    id: i32,
+    expected_rev: i32,
+) -> Result<Option<anyhow::Error>> {
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let current = client
+        .work_items_client()
+        .get_work_item(&creds.organization, id, project)
+        .await?;
+    let current_rev = current.rev.unwrap_or_default();
+    if current_rev == expected_rev {
+        return Ok(None);
+    }
+
+    let mut message = format!(
+        "Conflict: work item {id} is at revision {current_rev}, expected revision {expected_rev}"
+    );
+    let expected = client
+        .revisions_client()
+        .get(&creds.organization, id, expected_rev, project)
+        .await;
+    if let Ok(expected) = expected {
+        let diffs = diff_fields(&expected.fields, &current.fields);
+        if !diffs.is_empty() {
+            message.push_str("\nFields changed since then:");
+            for diff in diffs {
+                message.push_str(&format!(
+                    "\n  {}: {} → {}",
+                    diff.field,
+                    plain_value(diff.old.as_ref()),
+                    plain_value(diff.new.as_ref())
+                ));
+            }
+        }
+    }
+
+    Ok(Some(anyhow!(message)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn update(fields: serde_json::Value) -> WorkItemUpdate {
+        serde_json::from_value(json!({ "url": "", "fields": fields })).unwrap()
+    }
+
+    #[test]
+    fn test_state_changes() {
+        let updates = vec![
+            update(json!({
+                "System.State": { "newValue": "New" },
+                "System.ChangedDate": { "newValue": "2026-01-05T09:00:00Z" }
+            })),
+            update(json!({
+                "System.Title": { "oldValue": "A", "newValue": "B" },
+                "System.ChangedDate": { "oldValue": "2026-01-05T09:00:00Z", "newValue": "2026-01-06T09:00:00Z" }
+            })),
+            update(json!({
+                "System.State": { "oldValue": "New", "newValue": "Active" },
+                "System.ChangedDate": { "oldValue": "2026-01-06T09:00:00Z", "newValue": "2026-01-07T10:30:00Z" }
+            })),
+        ];
+
+        assert_eq!(
+            state_changes(&updates),
+            vec![
+                StateChange {
+                    from: None,
+                    to: "New".to_string(),
+                    date: "2026-01-05T09:00:00Z".to_string(),
+                },
+                StateChange {
+                    from: Some("New".to_string()),
+                    to: "Active".to_string(),
+                    date: "2026-01-07T10:30:00Z".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_fields_ignores_bookkeeping_fields() {
+        let old = json!({
+            "System.Rev": 3,
+            "System.State": "Active",
+            "Microsoft.VSTS.Common.Severity": "3 - Medium",
+            "System.Title": "Crash"
+        });
+        let new = json!({
+            "System.Rev": 5,
+            "System.State": "Active",
+            "Microsoft.VSTS.Common.Severity": "1 - Critical",
+            "System.Tags": "prod"
+        });
+
+        assert_eq!(
+            diff_fields(&old, &new),
+            vec![
+                FieldDiff {
+                    field: "Microsoft.VSTS.Common.Severity".to_string(),
+                    old: Some(json!("3 - Medium")),
+                    new: Some(json!("1 - Critical")),
+                },
+                FieldDiff {
+                    field: "System.Tags".to_string(),
+                    old: None,
+                    new: Some(json!("prod")),
+                },
+                FieldDiff {
+                    field: "System.Title".to_string(),
+                    old: Some(json!("Crash")),
+                    new: None,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,524 @@
+use crate::auth::get_credentials;
+use crate::boards;
+use crate::web;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItemTypeCategory;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Backlog categories for each level of nesting in a Markdown plan, with the
+/// type used when the process doesn't define the category
+const MARKDOWN_HIERARCHY: [(&str, &str); 4] = [
+    ("Microsoft.EpicCategory", "Epic"),
+    ("Microsoft.FeatureCategory", "Feature"),
+    ("Microsoft.RequirementCategory", "User Story"),
+    ("Microsoft.TaskCategory", "Task"),
+];
+
+/// A work item read from an import file
+#[derive(Debug, PartialEq)]
+pub struct ImportItem {
+    pub work_item_type: String,
+    pub title: String,
+    pub fields: Vec<(String, Value)>,
+    /// Where the item hangs in the hierarchy
+    pub parent: Option<ImportParent>,
+    pub depth: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ImportParent {
+    /// Another item in the same file, by its position
+    Item(usize),
+    /// An existing work item
+    Existing(i32),
+}
+
+/// Maps the well known CSV column headers to field reference names
+fn default_field_for_column(column: &str) -> Option<&'static str> {
+    match column.trim().to_lowercase().as_str() {
+        "title" => Some("System.Title"),
+        "description" => Some("System.Description"),
+        "state" => Some("System.State"),
+        "assigned to" | "assignee" => Some("System.AssignedTo"),
+        "tags" => Some("System.Tags"),
+        "area" | "area path" => Some("System.AreaPath"),
+        "iteration" | "iteration path" => Some("System.IterationPath"),
+        "priority" => Some("Microsoft.VSTS.Common.Priority"),
+        "story points" => Some("Microsoft.VSTS.Scheduling.StoryPoints"),
+        "effort" => Some("Microsoft.VSTS.Scheduling.Effort"),
+        "remaining work" => Some("Microsoft.VSTS.Scheduling.RemainingWork"),
+        "acceptance criteria" => Some("Microsoft.VSTS.Common.AcceptanceCriteria"),
+        _ => None,
+    }
+}
+
+/// Parses `Column=Field.Reference` mappings given on the command line
+pub fn parse_mappings(mappings: &[String]) -> Result<Vec<(String, String)>> {
+    mappings
+        .iter()
+        .map(|mapping| {
+            let (column, field) = mapping.split_once('=').ok_or_else(|| {
+                anyhow!(
+                    "Invalid mapping '{}', expected 'Column=Field.Reference'",
+                    mapping
+                )
+            })?;
+            Ok((column.trim().to_lowercase(), field.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Splits CSV content into rows of fields, honouring quoted fields
+pub fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => row.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows.retain(|row| row.iter().any(|f| !f.trim().is_empty()));
+    rows
+}
+
+/// Reads work items from CSV rows, using the first row as the header.
+///
+/// A 'Type' or 'Work Item Type' column sets the type and a 'Parent' column
+/// links a row to an existing work item.
+pub fn items_from_csv(
+    content: &str,
+    mappings: &[(String, String)],
+    default_type: &str,
+    parent: Option<i32>,
+) -> Result<Vec<ImportItem>> {
+    let mut rows = parse_csv(content).into_iter();
+    let header: Vec<String> = rows
+        .next()
+        .ok_or_else(|| anyhow!("The CSV file is empty"))?
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+
+    let mut items = Vec::new();
+    for (index, row) in rows.enumerate() {
+        let line = index + 2;
+        let mut title = None;
+        let mut work_item_type = default_type.to_string();
+        let mut item_parent = parent.map(ImportParent::Existing);
+        let mut fields = Vec::new();
+
+        for (column, value) in header.iter().zip(row.iter()) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+
+            let mapped = mappings
+                .iter()
+                .find(|(c, _)| c == column)
+                .map(|(_, field)| field.as_str());
+            let field = match (mapped, column.as_str()) {
+                (Some(field), _) => field,
+                (None, "type" | "work item type") => {
+                    work_item_type = value.to_string();
+                    continue;
+                }
+                (None, "parent") => {
+                    let id = value.parse::<i32>().map_err(|_| {
+                        anyhow!(
+                            "Invalid parent '{}' on line {}, must be a number",
+                            value,
+                            line
+                        )
+                    })?;
+                    item_parent = Some(ImportParent::Existing(id));
+                    continue;
+                }
+                (None, column) if column.contains('.') => column,
+                (None, column) => match default_field_for_column(column) {
+                    Some(field) => field,
+                    None => continue,
+                },
+            };
+
+            if field == "System.Title" {
+                title = Some(value.to_string());
+            } else {
+                fields.push((field.to_string(), json!(value)));
+            }
+        }
+
+        let title = title.ok_or_else(|| anyhow!("Missing title on line {}", line))?;
+        items.push(ImportItem {
+            work_item_type,
+            title,
+            fields,
+            parent: item_parent,
+            depth: 0,
+        });
+    }
+
+    Ok(items)
+}
+
+/// Returns the indentation and text of a Markdown bullet, if the line is one
+fn parse_bullet(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let indent: usize = line[..line.len() - trimmed.len()]
+        .chars()
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum();
+
+    let text = if let Some(rest) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+        .or_else(|| trimmed.strip_prefix("+ "))
+    {
+        rest
+    } else {
+        let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+        trimmed[digits..]
+            .strip_prefix(". ")
+            .filter(|_| digits > 0)?
+    };
+
+    // Task list checkboxes are not part of the title
+    let text = text
+        .strip_prefix("[ ] ")
+        .or_else(|| text.strip_prefix("[x] "))
+        .or_else(|| text.strip_prefix("[X] "))
+        .unwrap_or(text);
+
+    Some((indent, text.trim()))
+}
+
+/// Picks the default work item type of each Markdown level's backlog category,
+/// e.g. 'Product Backlog Item' as the requirement type of a Scrum project
+fn hierarchy_from_categories(categories: &[WorkItemTypeCategory]) -> Vec<String> {
+    MARKDOWN_HIERARCHY
+        .iter()
+        .map(|(category, fallback)| {
+            categories
+                .iter()
+                .find(|c| c.reference_name.as_deref() == Some(*category))
+                .and_then(|c| c.default_work_item_type.as_ref())
+                .and_then(|t| t.name.clone())
+                .unwrap_or_else(|| fallback.to_string())
+        })
+        .collect()
+}
+
+/// Looks up the work item types for the levels of a Markdown plan from the project's process
+async fn markdown_hierarchy(project: &str) -> Result<Vec<String>> {
+    let creds = get_credentials()?;
+    let categories = boards::create_client()?
+        .work_item_type_categories_client()
+        .list(creds.organization, project)
+        .await
+        .map_err(|e| anyhow!("Failed to get work item type categories: {}", e))?;
+    Ok(hierarchy_from_categories(&categories.value))
+}
+
+/// Reads work items from nested Markdown bullets.
+///
+/// Each level of nesting becomes the next type in `hierarchy`, e.g.
+/// Epic > Feature > User Story > Task. Deeper levels use the last type.
+pub fn items_from_markdown(
+    content: &str,
+    parent: Option<i32>,
+    hierarchy: &[String],
+) -> Vec<ImportItem> {
+    let mut items: Vec<ImportItem> = Vec::new();
+    // Indentation and item index of the open bullets, outermost first
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+
+    for line in content.lines() {
+        let Some((indent, title)) = parse_bullet(line) else {
+            continue;
+        };
+        if title.is_empty() {
+            continue;
+        }
+
+        while stack.last().is_some_and(|(open, _)| *open >= indent) {
+            stack.pop();
+        }
+
+        let depth = stack.len();
+        let work_item_type = &hierarchy[depth.min(hierarchy.len() - 1)];
+        let item_parent = match stack.last() {
+            Some((_, index)) => Some(ImportParent::Item(*index)),
+            None => parent.map(ImportParent::Existing),
+        };
+
+        stack.push((indent, items.len()));
+        items.push(ImportItem {
+            work_item_type: work_item_type.to_string(),
+            title: title.to_string(),
+            fields: Vec::new(),
+            parent: item_parent,
+            depth,
+        });
+    }
+
+    items
+}
+
+fn is_markdown(file: &str) -> bool {
+    matches!(
+        Path::new(file)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .as_deref(),
+        Some("md" | "markdown")
+    )
+}
+
+fn display_dry_run(items: &[ImportItem]) -> Result<()> {
+    let work_items_url = web::api_url(None, &["wit", "workItems"])?;
+    for (index, item) in items.iter().enumerate() {
+        // Items created from the same file are referenced by their position
+        let parent = item.parent.map(|p| match p {
+            ImportParent::Item(index) => format!("${}", index + 1),
+            ImportParent::Existing(id) => id.to_string(),
+        });
+        let patch = boards::create_patch_operations(
+            work_items_url.as_str(),
+            &item.title,
+            &item.fields,
+            parent.as_deref(),
+        );
+        println!(
+            "{}",
+            format!("${} {}: {}", index + 1, item.work_item_type, item.title).bold()
+        );
+        println!("{}", serde_json::to_string_pretty(&patch)?);
+    }
+    println!();
+    println!("Dry run: {} work items would be created", items.len());
+    Ok(())
+}
+
+async fn create_items(project: &str, items: &[ImportItem]) -> usize {
+    let mut created: Vec<Option<i32>> = Vec::with_capacity(items.len());
+    let mut failures = 0;
+
+    for item in items {
+        let indent = "  ".repeat(item.depth);
+        let parent = match item.parent {
+            Some(ImportParent::Item(index)) => match created[index] {
+                Some(id) => Some(id),
+                None => {
+                    eprintln!(
+                        "{indent}⏭️  Skipped {} '{}': parent was not created",
+                        item.work_item_type, item.title
+                    );
+                    created.push(None);
+                    failures += 1;
+                    continue;
+                }
+            },
+            Some(ImportParent::Existing(id)) => Some(id),
+            None => None,
+        };
+
+        match boards::create_work_item(
+            project,
+            &item.work_item_type,
+            &item.title,
+            &item.fields,
+            parent,
+        )
+        .await
+        {
+            Ok(work_item) => {
+                println!(
+                    "{indent}✅ Created {} #{}: {}",
+                    item.work_item_type, work_item.id, item.title
+                );
+                created.push(Some(work_item.id));
+            }
+            Err(e) => {
+                eprintln!(
+                    "{indent}❌ Failed to create {} '{}': {e}",
+                    item.work_item_type, item.title
+                );
+                created.push(None);
+                failures += 1;
+            }
+        }
+    }
+
+    failures
+}
+
+pub async fn import_work_items(
+    project: &str,
+    file: &str,
+    mappings: &[String],
+    default_type: &str,
+    types: &[String],
+    parent: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    let parent = parent
+        .map(|id| {
+            id.parse::<i32>()
+                .map_err(|_| anyhow!("Invalid work item ID, must be a number"))
+        })
+        .transpose()?;
+    let content = tokio::fs::read_to_string(file)
+        .await
+        .map_err(|e| anyhow!("Failed to read import file '{}': {}", file, e))?;
+
+    let items = if is_markdown(file) {
+        let hierarchy = if types.is_empty() {
+            markdown_hierarchy(project).await?
+        } else {
+            types.iter().map(|t| t.trim().to_string()).collect()
+        };
+        items_from_markdown(&content, parent, &hierarchy)
+    } else {
+        items_from_csv(&content, &parse_mappings(mappings)?, default_type, parent)?
+    };
+
+    if items.is_empty() {
+        println!("No work items found in {file}");
+        return Ok(());
+    }
+
+    if dry_run {
+        return display_dry_run(&items);
+    }
+
+    let failures = create_items(project, &items).await;
+    println!();
+    if failures > 0 {
+        return Err(anyhow!(
+            "{} of {} work items were not created",
+            failures,
+            items.len()
+        ));
+    }
+    println!(
+        "{}",
+        format!("✅ Imported {} work items", items.len()).green()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_handles_quotes() {
+        let rows = parse_csv("Title,Tags\n\"Fix, then ship\",\"a \"\"b\"\"\"\r\n\nSecond,\n");
+        assert_eq!(
+            rows,
+            vec![
+                vec!["Title", "Tags"],
+                vec!["Fix, then ship", "a \"b\""],
+                vec!["Second", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_items_from_csv_maps_columns() {
+        let csv = "Type,Title,Estimate,Priority,Parent,Notes\nBug,Crash on save,3,1,42,ignored\n,Add export,,,,\n";
+        let mappings =
+            parse_mappings(&["Estimate=Microsoft.VSTS.Scheduling.StoryPoints".to_string()])
+                .unwrap();
+        let items = items_from_csv(csv, &mappings, "User Story", None).unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].work_item_type, "Bug");
+        assert_eq!(items[0].title, "Crash on save");
+        assert_eq!(
+            items[0].fields,
+            vec![
+                (
+                    "Microsoft.VSTS.Scheduling.StoryPoints".to_string(),
+                    json!("3")
+                ),
+                ("Microsoft.VSTS.Common.Priority".to_string(), json!("1")),
+            ]
+        );
+        assert_eq!(items[0].parent, Some(ImportParent::Existing(42)));
+        assert_eq!(items[1].work_item_type, "User Story");
+        assert_eq!(items[1].parent, None);
+    }
+
+    #[test]
+    fn test_items_from_markdown_builds_hierarchy() {
+        let plan = "# Plan\n\n- Checkout\n  - Payments\n    - Pay by card\n      - [ ] Build form\n      - Validate\n  - Receipts\n* Search\n";
+        let hierarchy: Vec<String> = ["Epic", "Feature", "User Story", "Task"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        let items = items_from_markdown(plan, Some(7), &hierarchy);
+        let summary: Vec<(&str, &str, Option<ImportParent>)> = items
+            .iter()
+            .map(|i| (i.work_item_type.as_str(), i.title.as_str(), i.parent))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("Epic", "Checkout", Some(ImportParent::Existing(7))),
+                ("Feature", "Payments", Some(ImportParent::Item(0))),
+                ("User Story", "Pay by card", Some(ImportParent::Item(1))),
+                ("Task", "Build form", Some(ImportParent::Item(2))),
+                ("Task", "Validate", Some(ImportParent::Item(2))),
+                ("Feature", "Receipts", Some(ImportParent::Item(0))),
+                ("Epic", "Search", Some(ImportParent::Existing(7))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hierarchy_from_categories() {
+        let categories: Vec<WorkItemTypeCategory> = serde_json::from_value(json!([
+            {
+                "referenceName": "Microsoft.RequirementCategory",
+                "url": "",
+                "defaultWorkItemType": { "name": "Product Backlog Item", "url": "" }
+            },
+            {
+                "referenceName": "Microsoft.TaskCategory",
+                "url": "",
+                "defaultWorkItemType": { "name": "Task", "url": "" }
+            }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            hierarchy_from_categories(&categories),
+            vec!["Epic", "Feature", "Product Backlog Item", "Task"]
+        );
+    }
+}
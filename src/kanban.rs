@@ -0,0 +1,425 @@
+use crate::auth::get_credentials;
+use crate::boards::{self, sanitize_wiql_value};
+use crate::project::get_project_or_default;
+use crate::query::{self, format_field_value};
+use crate::sprint::{self, team_or_default};
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::json_patch_operation::Op;
+use azure_devops_rust_api::wit::models::{JsonPatchOperation, WorkItem};
+use azure_devops_rust_api::work::models::{self, board_column::ColumnType};
+use clap::Subcommand;
+use colored::Colorize;
+use serde_json::json;
+
+#[derive(Subcommand, Clone)]
+pub enum BoardSubCommands {
+    /// Show a Kanban board with its columns and cards
+    Show {
+        /// Team name (defaults to the project's default team)
+        #[clap(long)]
+        team: Option<String>,
+        /// Board name, e.g. 'Stories' or 'Features'
+        #[clap(long, default_value = "Stories")]
+        board: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        /// Maximum number of cards to show per column (default: 200)
+        #[clap(long, default_value = "200")]
+        limit: usize,
+    },
+    /// Move a card to another board column
+    Move {
+        /// ID of the work item to move
+        #[clap(short, long)]
+        id: String,
+        /// Name of the target column
+        #[clap(short, long)]
+        column: String,
+        /// Place the card in the Done half of a split column
+        #[clap(long)]
+        done: bool,
+        /// Team name (defaults to the project's default team)
+        #[clap(long)]
+        team: Option<String>,
+        /// Board name, e.g. 'Stories' or 'Features'
+        #[clap(long, default_value = "Stories")]
+        board: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+}
+
+/// Fields needed to render a card
+const CARD_FIELDS: [&str; 3] = ["System.Title", "System.AssignedTo", "System.WorkItemType"];
+
+pub async fn handle_command(subcommand: &BoardSubCommands) -> Result<()> {
+    match subcommand {
+        BoardSubCommands::Show {
+            team,
+            board,
+            project,
+            limit,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let team = team_or_default(&project_name, team.as_deref()).await?;
+            if let Err(e) = show_board(&project_name, &team, board, *limit).await {
+                eprintln!("❌ Failed to show board: {e}");
+                return Err(e);
+            }
+        }
+        BoardSubCommands::Move {
+            id,
+            column,
+            done,
+            team,
+            board,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let team = team_or_default(&project_name, team.as_deref()).await?;
+            match move_card(&project_name, &team, board, id, column, *done).await {
+                Ok(_) => println!(
+                    "{}",
+                    format!("✅ Moved work item {id} to column '{column}'").green()
+                ),
+                Err(e) => {
+                    eprintln!("❌ Failed to move work item: {e}");
+                    return Err(e);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn get_board(project: &str, team: &str, board: &str) -> Result<models::Board> {
+    let creds = get_credentials()?;
+    sprint::create_client()?
+        .boards_client()
+        .get(creds.organization, project, board, team)
+        .await
+        .map_err(|e| anyhow!("Board '{}' not found for team '{}': {}", board, team, e))
+}
+
+/// The column and done field reference names of a board
+fn board_fields(board: &models::Board) -> Result<(String, Option<String>)> {
+    let fields = board.fields.as_ref();
+    let column_field = fields
+        .and_then(|f| f.column_field.as_ref())
+        .and_then(|f| f.reference_name.clone())
+        .ok_or_else(|| anyhow!("Board has no column field"))?;
+    let done_field = fields
+        .and_then(|f| f.done_field.as_ref())
+        .and_then(|f| f.reference_name.clone());
+    Ok((column_field, done_field))
+}
+
+fn column_name(column: &models::BoardColumn) -> &str {
+    column.name.as_deref().unwrap_or("")
+}
+
+/// Finds a board column by name, ignoring case
+fn find_column<'a>(
+    columns: &'a [models::BoardColumn],
+    name: &str,
+) -> Option<&'a models::BoardColumn> {
+    columns
+        .iter()
+        .find(|c| column_name(c).eq_ignore_ascii_case(name.trim()))
+}
+
+/// Whether a column holds more cards than its WIP limit allows.
+///
+/// The first and last columns of a board have no WIP limit.
+fn is_over_wip_limit(column: &models::BoardColumn, count: usize) -> bool {
+    match column.column_type {
+        Some(ColumnType::InProgress) => column
+            .item_limit
+            .filter(|limit| *limit > 0)
+            .map(|limit| count > limit as usize)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn is_done(work_item: &WorkItem, done_field: Option<&str>) -> bool {
+    done_field
+        .and_then(|field| work_item.fields.get(field))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn display_card(work_item: &WorkItem) {
+    let title = work_item
+        .fields
+        .get("System.Title")
+        .map(format_field_value)
+        .unwrap_or_default();
+    let assignee = work_item
+        .fields
+        .get("System.AssignedTo")
+        .map(format_field_value)
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| "Unassigned".to_string());
+    let truncated_title = if title.chars().count() > 60 {
+        format!("{}...", title.chars().take(57).collect::<String>())
+    } else {
+        title
+    };
+    println!(
+        "    #{:<8} {:<60} {}",
+        work_item.id,
+        truncated_title,
+        assignee.dimmed()
+    );
+}
+
+/// Renders the board. Column headers count every card in `work_items`, while at most
+/// `limit` cards are listed per column.
+fn display_board(
+    board: &models::Board,
+    work_items: &[WorkItem],
+    done_field: Option<&str>,
+    limit: usize,
+) {
+    let column_field = board
+        .fields
+        .as_ref()
+        .and_then(|f| f.column_field.as_ref())
+        .and_then(|f| f.reference_name.as_deref())
+        .unwrap_or_default();
+
+    println!(
+        "\n📋 {}",
+        board
+            .board_reference
+            .name
+            .as_deref()
+            .unwrap_or("Board")
+            .bold()
+    );
+
+    for column in &board.columns {
+        let cards: Vec<&WorkItem> = work_items
+            .iter()
+            .filter(|wi| {
+                wi.fields
+                    .get(column_field)
+                    .and_then(|v| v.as_str())
+                    .map(|c| c == column_name(column))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        let is_outgoing = matches!(column.column_type, Some(ColumnType::Outgoing));
+        let wip_limit = column
+            .item_limit
+            .filter(|limit| *limit > 0)
+            .filter(|_| matches!(column.column_type, Some(ColumnType::InProgress)));
+        let header = match wip_limit {
+            Some(wip_limit) => format!("{} ({}/{})", column_name(column), cards.len(), wip_limit),
+            None if is_outgoing => format!("{} (cards not shown)", column_name(column)),
+            None => format!("{} ({})", column_name(column), cards.len()),
+        };
+
+        println!();
+        if is_over_wip_limit(column, cards.len()) {
+            println!("{}", format!("{header} ⚠ over WIP limit").red().bold());
+        } else {
+            println!("{}", header.bold());
+        }
+        println!("{}", "-".repeat(80));
+
+        if is_outgoing {
+            continue;
+        }
+
+        let hidden = cards.len().saturating_sub(limit);
+        let shown = &cards[..cards.len() - hidden];
+        if column.is_split.unwrap_or(false) {
+            let (done, doing): (Vec<&WorkItem>, Vec<&WorkItem>) =
+                shown.iter().partition(|wi| is_done(wi, done_field));
+            for (label, cards) in [("Doing", doing), ("Done", done)] {
+                println!("  {}", label.cyan());
+                for card in cards {
+                    display_card(card);
+                }
+            }
+        } else {
+            for card in shown {
+                display_card(card);
+            }
+        }
+        if hidden > 0 {
+            println!("    {}", format!("... and {hidden} more").dimmed());
+        }
+    }
+    println!();
+}
+
+pub async fn show_board(project: &str, team: &str, board_name: &str, limit: usize) -> Result<()> {
+    let board = get_board(project, team, board_name).await?;
+    let (column_field, done_field) = board_fields(&board)?;
+
+    // Cards in the last column are not loaded, since it tends to hold the whole history
+    let mut wiql = format!(
+        "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = '{}' AND [{}] <> ''",
+        sanitize_wiql_value(project),
+        column_field
+    );
+    for column in &board.columns {
+        if matches!(column.column_type, Some(ColumnType::Outgoing)) {
+            wiql.push_str(&format!(
+                " AND [{}] <> '{}'",
+                column_field,
+                sanitize_wiql_value(column_name(column))
+            ));
+        }
+    }
+    wiql.push_str(" ORDER BY [Microsoft.VSTS.Common.StackRank] ASC");
+
+    let result = query::execute_query(project, Some(&wiql), None).await?;
+    let ids: Vec<i32> = result.work_items.iter().filter_map(|wi| wi.id).collect();
+
+    let mut fields: Vec<String> = CARD_FIELDS.iter().map(|f| f.to_string()).collect();
+    fields.push(column_field);
+    if let Some(done_field) = &done_field {
+        fields.push(done_field.clone());
+    }
+
+    let work_items = if ids.is_empty() {
+        Vec::new()
+    } else {
+        let creds = get_credentials()?;
+        let client = boards::create_client()?;
+        boards::get_work_items_details(&client, &creds.organization, project, &ids, &fields).await
+    };
+
+    display_board(&board, &work_items, done_field.as_deref(), limit);
+    Ok(())
+}
+
+pub async fn move_card(
+    project: &str,
+    team: &str,
+    board_name: &str,
+    id: &str,
+    column: &str,
+    done: bool,
+) -> Result<WorkItem> {
+    let id_int = id
+        .parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))?;
+
+    let board = get_board(project, team, board_name).await?;
+    let (column_field, done_field) = board_fields(&board)?;
+    let target = find_column(&board.columns, column).ok_or_else(|| {
+        let names: Vec<&str> = board.columns.iter().map(column_name).collect();
+        anyhow!(
+            "Column '{}' not found. Available columns: {}",
+            column,
+            names.join(", ")
+        )
+    })?;
+    if done && !target.is_split.unwrap_or(false) {
+        return Err(anyhow!(
+            "Column '{}' is not split into Doing and Done",
+            column_name(target)
+        ));
+    }
+
+    let work_item = boards::get_work_item(project, id, None).await?;
+    let field_str = |name: &str| {
+        work_item
+            .fields
+            .get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let mut patch_operations = vec![JsonPatchOperation {
+        from: None,
+        op: Some(Op::Add),
+        path: Some(format!("/fields/{column_field}")),
+        value: Some(json!(column_name(target))),
+    }];
+
+    if let Some(done_field) = &done_field {
+        patch_operations.push(JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some(format!("/fields/{done_field}")),
+            value: Some(json!(done)),
+        });
+    }
+
+    // Keep the state in line with the column's state mapping for this type
+    let mapped_state = target
+        .state_mappings
+        .as_ref()
+        .and_then(|m| m.get(field_str("System.WorkItemType")))
+        .and_then(|s| s.as_str());
+    if let Some(state) = mapped_state {
+        if state != field_str("System.State") {
+            patch_operations.push(JsonPatchOperation {
+                from: None,
+                op: Some(Op::Add),
+                path: Some("/fields/System.State".to_owned()),
+                value: Some(json!(state)),
+            });
+        }
+    }
+
+    let creds = get_credentials()?;
+    let work_item = boards::create_client()?
+        .work_items_client()
+        .update(creds.organization, patch_operations, id_int, project)
+        .await?;
+    Ok(work_item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, column_type: ColumnType, item_limit: i32) -> models::BoardColumn {
+        models::BoardColumn {
+            column_type: Some(column_type),
+            description: None,
+            id: None,
+            is_split: Some(false),
+            item_limit: Some(item_limit),
+            name: Some(name.to_string()),
+            state_mappings: None,
+        }
+    }
+
+    #[test]
+    fn test_is_over_wip_limit() {
+        let active = column("Active", ColumnType::InProgress, 3);
+        assert!(!is_over_wip_limit(&active, 3));
+        assert!(is_over_wip_limit(&active, 4));
+
+        let unlimited = column("Review", ColumnType::InProgress, 0);
+        assert!(!is_over_wip_limit(&unlimited, 10));
+
+        let new = column("New", ColumnType::Incoming, 1);
+        assert!(!is_over_wip_limit(&new, 10));
+    }
+
+    #[test]
+    fn test_find_column_ignores_case() {
+        let columns = vec![
+            column("New", ColumnType::Incoming, 0),
+            column("Code Review", ColumnType::InProgress, 5),
+        ];
+        assert_eq!(
+            find_column(&columns, "code review").and_then(|c| c.name.as_deref()),
+            Some("Code Review")
+        );
+        assert!(find_column(&columns, "Testing").is_none());
+    }
+}
@@ -2,14 +2,34 @@ use crate::auth::{login, logout};
 use crate::project::{get_default_project, save_default_project};
 use clap::{CommandFactory, Parser, Subcommand};
 
+mod attachments;
 mod auth;
 mod boards;
+mod bulk;
+mod burndown;
+mod capacity;
+mod classification;
 mod config;
+mod export;
+mod history;
+mod import;
+mod kanban;
 mod pipelines;
 mod pr;
 mod project;
 mod projects;
+mod query;
+mod recycle_bin;
+mod relations;
 mod repos;
+mod roadmap;
+mod sprint;
+mod standup;
+mod states;
+mod tags;
+mod templates;
+mod watch;
+mod web;
 mod wiki;
 
 #[derive(Parser)]
@@ -50,6 +70,15 @@ enum Commands {
         #[clap(subcommand)]
         subcommand: projects::ProjectsSubCommands,
     },
+    /// Report your recent activity across work items, pull requests and pipelines as Markdown
+    Standup {
+        /// Start of the report: 'today', 'yesterday' (previous working day), e.g. '3d', or YYYY-MM-DD
+        #[clap(long, default_value = "yesterday", value_parser = boards::parse_changed_since)]
+        since: boards::ChangedSince,
+        /// Team project to report on, can be repeated (defaults to all projects)
+        #[clap(short, long = "project")]
+        projects: Vec<String>,
+    },
     /// Manage Azure DevOps wikis
     Wiki {
         #[clap(subcommand)]
@@ -89,6 +118,12 @@ async fn main() -> anyhow::Result<()> {
         Some(Commands::Projects { subcommand }) => {
             projects::handle_command(subcommand).await?;
         }
+        Some(Commands::Standup { since, projects }) => {
+            if let Err(e) = standup::standup(since, projects).await {
+                eprintln!("❌ Failed to create stand-up report: {e}");
+                return Err(e);
+            }
+        }
         Some(Commands::Wiki { subcommand }) => {
             wiki::handle_command(subcommand).await?;
         }
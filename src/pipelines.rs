@@ -1,9 +1,12 @@
 use crate::auth::get_credentials;
 use crate::project::get_project_or_default;
+use crate::web;
 use anyhow::{anyhow, Result};
+use azure_devops_rust_api::build;
 use azure_devops_rust_api::pipelines::{self, models, ClientBuilder};
 use clap::Subcommand;
 use colored::Colorize;
+use time::OffsetDateTime;
 
 #[derive(Subcommand, Clone)]
 pub enum PipelinesSubCommands {
@@ -33,6 +36,9 @@ pub enum PipelinesSubCommands {
         /// Build ID to show details for
         #[clap(short = 'b', long)]
         build_id: String,
+        /// Open in web browser
+        #[clap(long)]
+        web: bool,
     },
     /// Run a pipeline
     Run {
@@ -45,6 +51,76 @@ pub enum PipelinesSubCommands {
     },
 }
 
+/// A pipeline run, as reported by the build API
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggeredRun {
+    pub id: i64,
+    pub pipeline: String,
+    pub number: String,
+    pub branch: String,
+    pub status: String,
+}
+
+impl TriggeredRun {
+    fn from_build(build: &build::models::Build) -> Self {
+        // Finished builds have a result, the others only a status
+        let status = match &build.result {
+            Some(result) => format!("{result:?}"),
+            None => build
+                .status
+                .as_ref()
+                .map(|status| format!("{status:?}"))
+                .unwrap_or_default(),
+        };
+        TriggeredRun {
+            id: i64::from(build.id),
+            pipeline: build.definition.name.clone().unwrap_or_default(),
+            number: build.build_number.clone().unwrap_or_default(),
+            branch: build
+                .source_branch
+                .as_deref()
+                .unwrap_or_default()
+                .replace("refs/heads/", ""),
+            status,
+        }
+    }
+}
+
+fn create_build_client() -> Result<build::Client> {
+    let creds = get_credentials()?;
+    let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
+    Ok(build::ClientBuilder::new(credential).build())
+}
+
+/// Returns the pipeline runs queued for a user since the given Unix timestamp.
+///
+/// The pipelines API cannot filter runs by who requested them, so the build API is used.
+pub async fn get_runs_requested_for(
+    project: &str,
+    user_id: &str,
+    since_ts: i64,
+) -> Result<Vec<TriggeredRun>> {
+    let creds = get_credentials()?;
+    let builds = create_build_client()?
+        .builds_client()
+        .list(creds.organization, project)
+        .requested_for(user_id)
+        .min_time(OffsetDateTime::from_unix_timestamp(since_ts)?)
+        .await?
+        .value;
+    Ok(builds.iter().map(TriggeredRun::from_build).collect())
+}
+
+/// Returns a single build, as reported by the build API.
+pub async fn get_build_run(project: &str, build_id: i64) -> Result<TriggeredRun> {
+    let creds = get_credentials()?;
+    let build = create_build_client()?
+        .builds_client()
+        .get(creds.organization, project, i32::try_from(build_id)?)
+        .await?;
+    Ok(TriggeredRun::from_build(&build))
+}
+
 fn create_client() -> Result<pipelines::Client> {
     match get_credentials() {
         Ok(creds) => {
@@ -202,8 +278,14 @@ pub async fn handle_command(subcommand: &PipelinesSubCommands) -> Result<()> {
             id,
             project,
             build_id,
+            web,
         } => {
             let project_name = get_project_or_default(project.as_deref())?;
+            if *web {
+                let mut url = web::web_url(&[&project_name, "_build", "results"])?;
+                url.query_pairs_mut().append_pair("buildId", build_id);
+                return web::open_in_browser(url.as_str());
+            }
             println!(
                 "Showing details for build {build_id} of pipeline {id} in project {project_name}"
             );
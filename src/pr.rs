@@ -1,9 +1,11 @@
-use crate::auth::get_credentials;
+use crate::auth::{get_credentials, get_current_user};
 use crate::project::get_project_or_default;
 use crate::repos;
+use crate::web;
 use anyhow::Result;
 use azure_devops_rust_api::git::{self, ClientBuilder};
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
+use colored::Colorize;
 
 #[derive(Subcommand, Clone)]
 pub enum PullRequestsSubCommands {
@@ -39,9 +41,37 @@ pub enum PullRequestsSubCommands {
         #[clap(short, long)]
         project: Option<String>,
 
-        /// Name of the repository to list pull requests from
+        /// Name of the repository to list pull requests from (defaults to all repositories)
         #[clap(short, long)]
-        repo: String,
+        repo: Option<String>,
+
+        /// Status of the pull requests to list
+        #[clap(long, value_enum, default_value = "active")]
+        status: PullRequestStatus,
+
+        /// Only pull requests created by this user ('me' or a user ID)
+        #[clap(long)]
+        creator: Option<String>,
+
+        /// Only pull requests with this reviewer ('me' or a user ID)
+        #[clap(long)]
+        reviewer: Option<String>,
+
+        /// Only pull requests into this branch (e.g., 'main')
+        #[clap(long)]
+        target: Option<String>,
+
+        /// Only pull requests from this branch
+        #[clap(long)]
+        source: Option<String>,
+
+        /// Maximum number of pull requests to return (defaults to all)
+        #[clap(long, value_parser = clap::value_parser!(i32).range(1..))]
+        top: Option<i32>,
+
+        /// Number of pull requests to skip
+        #[clap(long, default_value = "0", value_parser = clap::value_parser!(i32).range(0..))]
+        skip: i32,
     },
     /// Show details of a specific pull request
     Show {
@@ -56,6 +86,10 @@ pub enum PullRequestsSubCommands {
         /// ID of the pull request to show
         #[clap(short, long)]
         id: String,
+
+        /// Open in web browser
+        #[clap(long)]
+        web: bool,
     },
     /// Show commits in a pull request
     Commits {
@@ -71,9 +105,59 @@ pub enum PullRequestsSubCommands {
         #[clap(short, long)]
         id: String,
     },
+    /// Approve a pull request
+    Approve {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+
+        /// ID of the pull request
+        #[clap(short, long)]
+        id: String,
+    },
+    /// Approve a pull request with suggestions
+    ApproveWithSuggestions {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+
+        /// ID of the pull request
+        #[clap(short, long)]
+        id: String,
+    },
+    /// Vote that a pull request is waiting for its author
+    WaitForAuthor {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+
+        /// ID of the pull request
+        #[clap(short, long)]
+        id: String,
+    },
+    /// Reject a pull request
+    Reject {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+
+        /// ID of the pull request
+        #[clap(short, long)]
+        id: String,
+    },
+    /// Remove your vote from a pull request
+    ResetVote {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+
+        /// ID of the pull request
+        #[clap(short, long)]
+        id: String,
+    },
 }
 
-fn create_client() -> Result<git::Client> {
+pub fn create_client() -> Result<git::Client> {
     match get_credentials() {
         Ok(creds) => {
             let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
@@ -84,6 +168,174 @@ fn create_client() -> Result<git::Client> {
     }
 }
 
+/// A reviewer's vote on a pull request
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Vote {
+    Approved,
+    ApprovedWithSuggestions,
+    NotVoted,
+    WaitingForAuthor,
+    Rejected,
+}
+
+impl Vote {
+    /// The number the API uses for the vote
+    pub fn value(self) -> i64 {
+        match self {
+            Vote::Approved => 10,
+            Vote::ApprovedWithSuggestions => 5,
+            Vote::NotVoted => 0,
+            Vote::WaitingForAuthor => -5,
+            Vote::Rejected => -10,
+        }
+    }
+
+    /// Reads a vote from its API value; unknown values count as no vote
+    pub fn from_value(value: i64) -> Self {
+        match value {
+            10 => Vote::Approved,
+            5 => Vote::ApprovedWithSuggestions,
+            -5 => Vote::WaitingForAuthor,
+            -10 => Vote::Rejected,
+            _ => Vote::NotVoted,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Vote::Approved => "Approved",
+            Vote::ApprovedWithSuggestions => "Approved with suggestions",
+            Vote::NotVoted => "No vote",
+            Vote::WaitingForAuthor => "Waiting for author",
+            Vote::Rejected => "Rejected",
+        }
+    }
+}
+
+/// Describes a reviewer's vote on a pull request
+pub fn vote_label(vote: i64) -> &'static str {
+    Vote::from_value(vote).label()
+}
+
+/// Pull request statuses that can be listed
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum PullRequestStatus {
+    Active,
+    Completed,
+    Abandoned,
+    All,
+}
+
+impl PullRequestStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PullRequestStatus::Active => "active",
+            PullRequestStatus::Completed => "completed",
+            PullRequestStatus::Abandoned => "abandoned",
+            PullRequestStatus::All => "all",
+        }
+    }
+}
+
+/// Turns a branch name into a ref name, e.g. 'main' into 'refs/heads/main'
+pub fn branch_ref(branch: &str) -> String {
+    if branch.starts_with("refs/") {
+        branch.to_string()
+    } else {
+        format!("refs/heads/{branch}")
+    }
+}
+
+/// Server-side search criteria for pull requests; unset criteria match everything
+#[derive(Debug, Default)]
+pub struct PullRequestSearch {
+    pub repository_id: Option<String>,
+    pub status: Option<String>,
+    pub creator_id: Option<String>,
+    pub reviewer_id: Option<String>,
+    pub source_ref: Option<String>,
+    pub target_ref: Option<String>,
+}
+
+/// Number of pull requests requested per page
+const PULL_REQUESTS_PAGE_SIZE: i32 = 100;
+
+/// Number of pull requests requested when looking up a user's pull requests
+const USER_PULL_REQUESTS_TOP: i32 = 100;
+
+/// Searches the pull requests of a project. Without `top` all pages are fetched.
+pub async fn search_pull_requests(
+    project: &str,
+    search: &PullRequestSearch,
+    skip: i32,
+    top: Option<i32>,
+) -> Result<Vec<git::models::GitPullRequest>> {
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let mut pull_requests = Vec::new();
+    let mut skip = skip;
+
+    loop {
+        let page_size = match top {
+            Some(top) => (top - pull_requests.len() as i32).min(PULL_REQUESTS_PAGE_SIZE),
+            None => PULL_REQUESTS_PAGE_SIZE,
+        };
+        if page_size <= 0 {
+            break;
+        }
+
+        let mut request = client
+            .pull_requests_client()
+            .get_pull_requests_by_project(&creds.organization, project)
+            .skip(skip)
+            .top(page_size);
+        if let Some(repository_id) = &search.repository_id {
+            request = request.search_criteria_repository_id(repository_id);
+        }
+        if let Some(status) = &search.status {
+            request = request.search_criteria_status(status);
+        }
+        if let Some(creator_id) = &search.creator_id {
+            request = request.search_criteria_creator_id(creator_id);
+        }
+        if let Some(reviewer_id) = &search.reviewer_id {
+            request = request.search_criteria_reviewer_id(reviewer_id);
+        }
+        if let Some(source_ref) = &search.source_ref {
+            request = request.search_criteria_source_ref_name(source_ref);
+        }
+        if let Some(target_ref) = &search.target_ref {
+            request = request.search_criteria_target_ref_name(target_ref);
+        }
+
+        let page = request.await?.value;
+        let count = page.len() as i32;
+        pull_requests.extend(page);
+        skip += count;
+        if count < page_size {
+            break;
+        }
+    }
+
+    Ok(pull_requests)
+}
+
+/// Returns the most recent pull requests of a project, in any status, created by
+/// or assigned for review to the given user ID
+pub async fn get_pull_requests_for_user(
+    project: &str,
+    creator_id: Option<&str>,
+    reviewer_id: Option<&str>,
+) -> Result<Vec<git::models::GitPullRequest>> {
+    let search = PullRequestSearch {
+        status: Some("all".to_string()),
+        creator_id: creator_id.map(String::from),
+        reviewer_id: reviewer_id.map(String::from),
+        ..Default::default()
+    };
+    search_pull_requests(project, &search, 0, Some(USER_PULL_REQUESTS_TOP)).await
+}
+
 pub async fn handle_command(subcommand: &PullRequestsSubCommands) -> anyhow::Result<()> {
     match subcommand {
         PullRequestsSubCommands::Create {
@@ -105,14 +357,64 @@ pub async fn handle_command(subcommand: &PullRequestsSubCommands) -> anyhow::Res
             )
             .await?;
         }
-        PullRequestsSubCommands::List { project, repo } => {
+        PullRequestsSubCommands::List {
+            project,
+            repo,
+            status,
+            creator,
+            reviewer,
+            target,
+            source,
+            top,
+            skip,
+        } => {
             let project_name = get_project_or_default(project.as_deref())?;
-            list_pull_requests(&project_name, repo).await?;
+            let search = PullRequestSearch {
+                repository_id: match repo {
+                    Some(repo) => Some(repos::get_repo(&project_name, repo).await?.id),
+                    None => None,
+                },
+                status: Some(status.as_str().to_string()),
+                creator_id: resolve_user(creator.as_deref()).await?,
+                reviewer_id: resolve_user(reviewer.as_deref()).await?,
+                source_ref: source.as_deref().map(branch_ref),
+                target_ref: target.as_deref().map(branch_ref),
+            };
+            list_pull_requests(&project_name, repo.as_deref(), &search, *skip, *top).await?;
         }
-        PullRequestsSubCommands::Show { project, repo, id } => {
+        PullRequestsSubCommands::Show {
+            project,
+            repo,
+            id,
+            web,
+        } => {
             let project_name = get_project_or_default(project.as_deref())?;
+            if *web {
+                let url = web::web_url(&[&project_name, "_git", repo, "pullrequest", id])?;
+                return web::open_in_browser(url.as_str());
+            }
             show_pull_request(&project_name, repo, id).await?;
         }
+        PullRequestsSubCommands::Approve { project, id } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            vote_on_pull_request(&project_name, id, Vote::Approved).await?;
+        }
+        PullRequestsSubCommands::ApproveWithSuggestions { project, id } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            vote_on_pull_request(&project_name, id, Vote::ApprovedWithSuggestions).await?;
+        }
+        PullRequestsSubCommands::WaitForAuthor { project, id } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            vote_on_pull_request(&project_name, id, Vote::WaitingForAuthor).await?;
+        }
+        PullRequestsSubCommands::Reject { project, id } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            vote_on_pull_request(&project_name, id, Vote::Rejected).await?;
+        }
+        PullRequestsSubCommands::ResetVote { project, id } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            vote_on_pull_request(&project_name, id, Vote::NotVoted).await?;
+        }
         PullRequestsSubCommands::Commits {
             ref project,
             repo,
@@ -184,17 +486,8 @@ async fn create_pull_request(
 
             let pr_client = client.pull_requests_client();
 
-            let source_ref = if source.starts_with("refs/heads/") {
-                source.to_string()
-            } else {
-                format!("refs/heads/{source}")
-            };
-
-            let target_ref = if target.starts_with("refs/heads/") {
-                target.to_string()
-            } else {
-                format!("refs/heads/{target}")
-            };
+            let source_ref = branch_ref(source);
+            let target_ref = branch_ref(target);
             println!("Creating pull request:");
             println!("  Repository: {repo}");
             println!("  Source branch: {source}");
@@ -238,39 +531,112 @@ async fn create_pull_request(
     }
 }
 
-async fn list_pull_requests(project: &str, repo: &str) -> Result<()> {
-    match get_credentials() {
-        Ok(creds) => {
-            let client = create_client()?;
-            let pr_client = client.pull_requests_client();
+/// Resolves a `--creator` or `--reviewer` value, where 'me' is the signed-in user
+async fn resolve_user(user: Option<&str>) -> Result<Option<String>> {
+    match user {
+        Some(user) if user.eq_ignore_ascii_case("me") => Ok(Some(get_current_user().await?.id)),
+        Some(user) => Ok(Some(user.to_string())),
+        None => Ok(None),
+    }
+}
 
-            let pull_requests = pr_client
-                .get_pull_requests_by_project(creds.organization, project)
-                .await?;
+async fn list_pull_requests(
+    project: &str,
+    repo: Option<&str>,
+    search: &PullRequestSearch,
+    skip: i32,
+    top: Option<i32>,
+) -> Result<()> {
+    let pull_requests = match search_pull_requests(project, search, skip, top).await {
+        Ok(pull_requests) => pull_requests,
+        Err(e) => {
+            eprintln!("Unable to retrieve pull requests");
+            return Err(e);
+        }
+    };
+
+    let scope = match repo {
+        Some(repo) => format!("repository '{repo}' in project '{project}'"),
+        None => format!("project '{project}'"),
+    };
+    if pull_requests.is_empty() {
+        println!("No pull requests found for {scope}");
+        return Ok(());
+    }
 
-            let filtered_prs: Vec<_> = pull_requests
-                .value
-                .into_iter()
-                .filter(|pr| pr.repository.name == repo)
-                .collect();
+    println!("Pull requests for {scope}:");
+    println!(
+        "{:<7} {:<20} {:<10} {:<20} {:<20} Title",
+        "ID", "Repository", "Status", "Created By", "Target"
+    );
+    println!("{}", "-".repeat(110));
+    for pr in &pull_requests {
+        println!(
+            "{:<7} {:<20} {:<10} {:<20} {:<20} {}",
+            format!("#{}", pr.pull_request_id),
+            pr.repository.name,
+            format!("{:?}", pr.status).to_lowercase(),
+            pr.created_by
+                .graph_subject_base
+                .display_name
+                .as_deref()
+                .unwrap_or_default(),
+            pr.target_ref_name.trim_start_matches("refs/heads/"),
+            pr.title.as_deref().unwrap_or_default()
+        );
+    }
+    println!("\nTotal: {} pull requests", pull_requests.len());
+    Ok(())
+}
 
-            if filtered_prs.is_empty() {
-                println!("No pull requests found for repository '{repo}' in project '{project}'");
-            } else {
-                println!("Pull requests for repository '{repo}' in project '{project}':");
-                for pr in filtered_prs {
-                    println!(
-                        "  #{} - {}",
-                        pr.pull_request_id,
-                        pr.title.unwrap_or_default()
-                    );
-                }
-            }
+/// Sets the signed-in user's vote on a pull request, adding them as a reviewer if needed
+async fn set_vote(project: &str, pr_id: i32, vote: Vote) -> Result<git::models::GitPullRequest> {
+    let user = get_current_user().await?;
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let pull_request = client
+        .pull_requests_client()
+        .get_pull_request_by_id(&creds.organization, pr_id, project)
+        .await?;
+
+    let mut reviewer =
+        git::models::IdentityRefWithVote::new(git::models::IdentityRef::new(user.id.clone()));
+    reviewer.vote = Some(vote.value());
+    client
+        .pull_request_reviewers_client()
+        .create_pull_request_reviewer(
+            &creds.organization,
+            reviewer,
+            &pull_request.repository.id,
+            pr_id,
+            &user.id,
+            project,
+        )
+        .await?;
+    Ok(pull_request)
+}
+
+async fn vote_on_pull_request(project: &str, id: &str, vote: Vote) -> Result<()> {
+    let pr_id = id
+        .parse::<i32>()
+        .map_err(|_| anyhow::anyhow!("Invalid pull request ID, must be a number"))?;
 
+    match set_vote(project, pr_id, vote).await {
+        Ok(pull_request) => {
+            let title = pull_request.title.as_deref().unwrap_or_default();
+            let message = if vote == Vote::NotVoted {
+                format!("✅ Reset your vote on pull request !{pr_id}: {title}")
+            } else {
+                format!(
+                    "✅ Voted '{}' on pull request !{pr_id}: {title}",
+                    vote.label()
+                )
+            };
+            println!("{}", message.green());
             Ok(())
         }
         Err(e) => {
-            eprintln!("Unable to retrieve pull requests");
+            eprintln!("❌ Failed to vote on pull request: {e}");
             Err(e)
         }
     }
@@ -311,3 +677,34 @@ async fn show_pull_request(project: &str, _repo: &str, id: &str) -> Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_ref() {
+        assert_eq!(branch_ref("main"), "refs/heads/main");
+        assert_eq!(
+            branch_ref("feature/checkout"),
+            "refs/heads/feature/checkout"
+        );
+        assert_eq!(branch_ref("refs/heads/main"), "refs/heads/main");
+    }
+
+    #[test]
+    fn test_vote_values() {
+        for (vote, value) in [
+            (Vote::Approved, 10),
+            (Vote::ApprovedWithSuggestions, 5),
+            (Vote::NotVoted, 0),
+            (Vote::WaitingForAuthor, -5),
+            (Vote::Rejected, -10),
+        ] {
+            assert_eq!(vote.value(), value);
+            assert_eq!(Vote::from_value(value), vote);
+        }
+        assert_eq!(Vote::from_value(3), Vote::NotVoted);
+        assert_eq!(vote_label(-5), "Waiting for author");
+    }
+}
@@ -1,6 +1,6 @@
 use crate::auth::get_credentials;
-use anyhow::Result;
-use azure_devops_rust_api::core::ClientBuilder;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::core::{models::TeamProject, ClientBuilder};
 use clap::Subcommand;
 
 #[derive(Subcommand, Clone)]
@@ -18,6 +18,50 @@ pub async fn handle_command(subcommand: &ProjectsSubCommands) -> Result<()> {
     Ok(())
 }
 
+/// Returns the names of all projects in the organization
+pub async fn get_project_names() -> Result<Vec<String>> {
+    let creds = get_credentials()?;
+    let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
+    let client = ClientBuilder::new(credential).build();
+
+    Ok(client
+        .projects_client()
+        .list(&creds.organization)
+        .await?
+        .value
+        .into_iter()
+        .map(|project| project.name)
+        .collect())
+}
+
+/// Name of a project's default team
+fn default_team_name(project: &TeamProject) -> Result<String> {
+    project
+        .default_team
+        .as_ref()
+        .and_then(|team| team.name.clone())
+        .ok_or_else(|| {
+            anyhow!(
+                "Project '{}' has no default team, specify one with --team",
+                project.team_project_reference.name
+            )
+        })
+}
+
+/// Returns the name of the project's default team, which may have been renamed
+/// from '<project> Team'
+pub async fn get_default_team(project: &str) -> Result<String> {
+    let creds = get_credentials()?;
+    let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
+    let client = ClientBuilder::new(credential).build();
+    default_team_name(
+        &client
+            .projects_client()
+            .get(&creds.organization, project)
+            .await?,
+    )
+}
+
 async fn list_projects() -> Result<()> {
     let creds = get_credentials()?;
     let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
@@ -66,3 +110,24 @@ async fn list_projects() -> Result<()> {
     println!("\nTotal: {} projects", projects.len());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_team_name() {
+        let project: TeamProject = serde_json::from_value(json!({
+            "name": "Fabrikam",
+            "visibility": "private",
+            "defaultTeam": { "name": "Web Platform" }
+        }))
+        .unwrap();
+        assert_eq!(default_team_name(&project).unwrap(), "Web Platform");
+
+        let without: TeamProject =
+            serde_json::from_value(json!({ "name": "Fabrikam", "visibility": "private" })).unwrap();
+        assert!(default_team_name(&without).is_err());
+    }
+}
@@ -0,0 +1,429 @@
+use crate::auth::get_credentials;
+use crate::boards::{create_client, get_work_items_details};
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::{self, work_item_query_result::QueryType};
+use colored::Colorize;
+use std::collections::HashMap;
+
+/// Columns shown when neither the query nor `--fields` specify any
+const DEFAULT_FIELDS: [&str; 4] = [
+    "System.WorkItemType",
+    "System.State",
+    "System.AssignedTo",
+    "System.Title",
+];
+
+/// Where the WIQL for a query comes from
+pub enum QuerySource<'a> {
+    Wiql(&'a str),
+    File(&'a str),
+    Saved(&'a str),
+}
+
+/// Runs a WIQL query, either inline or by the ID of a saved query
+pub async fn execute_query(
+    project: &str,
+    wiql: Option<&str>,
+    saved_query_id: Option<&str>,
+) -> Result<models::WorkItemQueryResult> {
+    let creds = get_credentials()?;
+    let client = create_client()?;
+
+    let result = match (wiql, saved_query_id) {
+        (Some(wiql), _) => {
+            client
+                .wiql_client()
+                .query_by_wiql(
+                    creds.organization,
+                    models::Wiql {
+                        query: Some(wiql.to_string()),
+                    },
+                    project.to_string(),
+                    String::new(),
+                )
+                .await?
+        }
+        (None, Some(id)) => {
+            client
+                .wiql_client()
+                .query_by_id(creds.organization, id, project.to_string(), String::new())
+                .await?
+        }
+        (None, None) => return Err(anyhow!("No query to execute")),
+    };
+
+    Ok(result)
+}
+
+async fn get_saved_query(project: &str, path: &str) -> Result<models::QueryHierarchyItem> {
+    let creds = get_credentials()?;
+    let query = create_client()?
+        .queries_client()
+        .get(creds.organization, project, path)
+        .expand("wiql")
+        .await
+        .map_err(|e| anyhow!("Saved query '{}' not found: {}", path, e))?;
+
+    if query.is_folder.unwrap_or(false) {
+        return Err(anyhow!("'{}' is a query folder, not a query", path));
+    }
+
+    Ok(query)
+}
+
+/// Returns the distinct work item IDs in the order they appear in the result
+pub fn result_ids(result: &models::WorkItemQueryResult) -> Vec<i32> {
+    let mut ids = Vec::new();
+    let mut push = |id: Option<i32>| {
+        if let Some(id) = id {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    };
+
+    if result.work_item_relations.is_empty() {
+        for item in &result.work_items {
+            push(item.id);
+        }
+    } else {
+        for link in &result.work_item_relations {
+            push(link.source.as_ref().and_then(|s| s.id));
+            push(link.target.as_ref().and_then(|t| t.id));
+        }
+    }
+
+    ids
+}
+
+/// Chooses the columns to display: explicit `--fields` first, then the query's own columns
+pub fn resolve_fields(fields: &[String], result: &models::WorkItemQueryResult) -> Vec<String> {
+    let chosen: Vec<String> = if !fields.is_empty() {
+        fields.iter().map(|f| f.trim().to_string()).collect()
+    } else if !result.columns.is_empty() {
+        result
+            .columns
+            .iter()
+            .filter_map(|c| c.reference_name.clone())
+            .collect()
+    } else {
+        DEFAULT_FIELDS.iter().map(|f| f.to_string()).collect()
+    };
+
+    // The ID is always shown as the first column
+    chosen
+        .into_iter()
+        .filter(|f| !f.is_empty() && f != "System.Id")
+        .collect()
+}
+
+/// Renders a field value for display, using display names for identity fields
+pub fn format_field_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Object(map) => map
+            .get("displayName")
+            .or_else(|| map.get("uniqueName"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| value.to_string()),
+        other => other.to_string(),
+    }
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() > width {
+        format!(
+            "{}...",
+            value
+                .chars()
+                .take(width.saturating_sub(3))
+                .collect::<String>()
+        )
+    } else {
+        value.to_string()
+    }
+}
+
+fn column_width(field: &str) -> usize {
+    match field {
+        "System.Title" => 50,
+        "System.AssignedTo" | "System.CreatedBy" | "System.ChangedBy" => 25,
+        _ => 20,
+    }
+}
+
+fn column_header(field: &str) -> &str {
+    field.rsplit('.').next().unwrap_or(field)
+}
+
+fn format_row(
+    id: i32,
+    indent: usize,
+    work_item: Option<&models::WorkItem>,
+    fields: &[String],
+) -> String {
+    let mut row = format!("{:<10}", format!("{}{}", "  ".repeat(indent), id));
+    for field in fields {
+        let value = work_item
+            .and_then(|wi| wi.fields.get(field))
+            .map(format_field_value)
+            .unwrap_or_default();
+        let width = column_width(field);
+        row.push_str(&format!(" {:<width$}", truncate(&value, width)));
+    }
+    row.trim_end().to_string()
+}
+
+fn display_query_header(fields: &[String]) {
+    let mut header = format!("{:<10}", "ID");
+    for field in fields {
+        let width = column_width(field);
+        header.push_str(&format!(" {:<width$}", column_header(field)));
+    }
+    println!("{}", header.trim_end().bold());
+    println!("{}", "-".repeat(header.trim_end().len().max(80)));
+}
+
+/// Orders the rows of a tree or one-hop query so children follow their parents.
+///
+/// One-hop results are limited to a depth of one, since their targets may
+/// also appear as sources elsewhere in the result.
+fn link_rows(
+    relations: &[models::WorkItemLink],
+    max_depth: usize,
+) -> Vec<(i32, usize, Option<String>)> {
+    let mut children: HashMap<i32, Vec<(i32, Option<String>)>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for link in relations {
+        let Some(target) = link.target.as_ref().and_then(|t| t.id) else {
+            continue;
+        };
+        match link.source.as_ref().and_then(|s| s.id) {
+            Some(source) => children
+                .entry(source)
+                .or_default()
+                .push((target, link.rel.clone())),
+            None => roots.push(target),
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut stack: Vec<(i32, usize, Option<String>)> =
+        roots.into_iter().rev().map(|id| (id, 0, None)).collect();
+    while let Some((id, depth, rel)) = stack.pop() {
+        rows.push((id, depth, rel));
+        if depth >= max_depth {
+            continue;
+        }
+        if let Some(kids) = children.get(&id) {
+            for (child, rel) in kids.iter().rev() {
+                stack.push((*child, depth + 1, rel.clone()));
+            }
+        }
+    }
+    rows
+}
+
+fn is_one_hop(result: &models::WorkItemQueryResult) -> bool {
+    matches!(result.query_type, Some(QueryType::OneHop))
+}
+
+/// The rows to display, as (id, depth, link type), in display order and limited to `limit`.
+///
+/// Link queries are laid out as a tree before the limit is applied, so the details
+/// can be fetched for exactly the rows that are shown.
+fn result_rows(
+    result: &models::WorkItemQueryResult,
+    limit: usize,
+) -> Vec<(i32, usize, Option<String>)> {
+    let is_link_query = matches!(
+        result.query_type,
+        Some(QueryType::Tree) | Some(QueryType::OneHop)
+    ) || !result.work_item_relations.is_empty();
+
+    if is_link_query {
+        let max_depth = if is_one_hop(result) { 1 } else { usize::MAX };
+        link_rows(&result.work_item_relations, max_depth)
+            .into_iter()
+            .take(limit)
+            .collect()
+    } else {
+        result
+            .work_items
+            .iter()
+            .filter_map(|item| item.id)
+            .take(limit)
+            .map(|id| (id, 0, None))
+            .collect()
+    }
+}
+
+fn display_query_results(
+    rows: &[(i32, usize, Option<String>)],
+    work_items: &[models::WorkItem],
+    fields: &[String],
+    is_one_hop: bool,
+) {
+    let by_id: HashMap<i32, &models::WorkItem> = work_items.iter().map(|wi| (wi.id, wi)).collect();
+
+    println!();
+    display_query_header(fields);
+
+    for (id, depth, rel) in rows {
+        let mut row = format_row(*id, *depth, by_id.get(id).copied(), fields);
+        if is_one_hop {
+            if let Some(rel) = rel {
+                row.push_str(&format!("  ({})", column_header(rel)).dimmed().to_string());
+            }
+        }
+        println!("{row}");
+    }
+
+    println!();
+    println!("Total: {} work items", rows.len());
+}
+
+pub async fn run_query(
+    project: &str,
+    source: QuerySource<'_>,
+    fields: &[String],
+    limit: i32,
+) -> Result<()> {
+    let result = match source {
+        QuerySource::Wiql(wiql) => execute_query(project, Some(wiql), None).await?,
+        QuerySource::File(path) => {
+            let wiql = tokio::fs::read_to_string(path)
+                .await
+                .map_err(|e| anyhow!("Failed to read WIQL file '{}': {}", path, e))?;
+            execute_query(project, Some(wiql.trim()), None).await?
+        }
+        QuerySource::Saved(path) => {
+            let query = get_saved_query(project, path).await?;
+            println!(
+                "📋 Running saved query: {}",
+                query.path.as_deref().unwrap_or(path)
+            );
+            let id = query
+                .id
+                .ok_or_else(|| anyhow!("Saved query '{}' has no ID", path))?;
+            execute_query(project, None, Some(&id)).await?
+        }
+    };
+
+    let limit = limit.max(0) as usize;
+    let fields = resolve_fields(fields, &result);
+    let rows = result_rows(&result, limit);
+    let mut ids: Vec<i32> = Vec::with_capacity(rows.len());
+    for (id, _, _) in &rows {
+        if !ids.contains(id) {
+            ids.push(*id);
+        }
+    }
+
+    if ids.is_empty() {
+        println!("No work items matched the query.");
+        return Ok(());
+    }
+
+    let creds = get_credentials()?;
+    let client = create_client()?;
+    let work_items =
+        get_work_items_details(&client, &creds.organization, project, &ids, &fields).await;
+
+    display_query_results(&rows, &work_items, &fields, is_one_hop(&result));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn reference(id: i32) -> Option<models::WorkItemReference> {
+        Some(models::WorkItemReference {
+            id: Some(id),
+            url: None,
+        })
+    }
+
+    fn link(source: Option<i32>, target: i32) -> models::WorkItemLink {
+        models::WorkItemLink {
+            rel: source.map(|_| "System.LinkTypes.Hierarchy-Forward".to_string()),
+            source: source.and_then(reference),
+            target: reference(target),
+        }
+    }
+
+    #[test]
+    fn test_format_field_value_uses_identity_display_name() {
+        let identity = json!({ "displayName": "Jane Doe", "uniqueName": "jane@contoso.com" });
+        assert_eq!(format_field_value(&identity), "Jane Doe");
+        assert_eq!(format_field_value(&json!("Active")), "Active");
+        assert_eq!(format_field_value(&json!(2)), "2");
+        assert_eq!(format_field_value(&serde_json::Value::Null), "");
+    }
+
+    #[test]
+    fn test_link_rows_nests_children_under_parents() {
+        let relations = vec![
+            link(None, 1),
+            link(Some(1), 2),
+            link(Some(2), 3),
+            link(None, 4),
+        ];
+        let rows: Vec<(i32, usize)> = link_rows(&relations, usize::MAX)
+            .into_iter()
+            .map(|(id, depth, _)| (id, depth))
+            .collect();
+        assert_eq!(rows, vec![(1, 0), (2, 1), (3, 2), (4, 0)]);
+
+        let one_hop: Vec<(i32, usize)> = link_rows(&relations, 1)
+            .into_iter()
+            .map(|(id, depth, _)| (id, depth))
+            .collect();
+        assert_eq!(one_hop, vec![(1, 0), (2, 1), (4, 0)]);
+    }
+
+    #[test]
+    fn test_result_rows_limits_the_tree() {
+        let result = models::WorkItemQueryResult {
+            query_type: Some(QueryType::Tree),
+            work_item_relations: vec![
+                link(None, 1),
+                link(None, 4),
+                link(Some(1), 2),
+                link(Some(4), 5),
+                link(Some(2), 3),
+            ],
+            ..Default::default()
+        };
+        let ids: Vec<i32> = result_rows(&result, 3)
+            .into_iter()
+            .map(|(id, _, _)| id)
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_resolve_fields_prefers_explicit_fields() {
+        let result = models::WorkItemQueryResult {
+            columns: vec![models::WorkItemFieldReference {
+                name: None,
+                reference_name: Some("System.Title".to_string()),
+                url: None,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(resolve_fields(&[], &result), vec!["System.Title"]);
+        assert_eq!(
+            resolve_fields(
+                &["System.Id".to_string(), " System.State".to_string()],
+                &result
+            ),
+            vec!["System.State"]
+        );
+    }
+}
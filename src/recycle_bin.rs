@@ -0,0 +1,257 @@
+use crate::auth::get_credentials;
+use crate::boards::create_client;
+use crate::project::get_project_or_default;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::{WorkItemDeleteReference, WorkItemDeleteUpdate};
+use clap::Subcommand;
+use colored::Colorize;
+use dialoguer::Confirm;
+
+/// Number of deleted work items requested per call
+const DELETED_BATCH_SIZE: usize = 200;
+
+#[derive(Subcommand, Clone)]
+pub enum RecycleBinSubCommands {
+    /// List the deleted work items in the recycle bin
+    List {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Restore a deleted work item from the recycle bin
+    Restore {
+        /// ID of the deleted work item
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Permanently destroy a deleted work item (cannot be undone)
+    Destroy {
+        /// ID of the deleted work item
+        #[clap(short, long)]
+        id: String,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+        /// Skip confirmation prompt and proceed directly
+        #[clap(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+pub async fn handle_command(subcommand: &RecycleBinSubCommands) -> Result<()> {
+    match subcommand {
+        RecycleBinSubCommands::List { project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            println!("Listing deleted work items in project: {project_name}");
+
+            match list_deleted_work_items(&project_name).await {
+                Ok(deleted) => display_deleted_work_items(&deleted),
+                Err(e) => {
+                    eprintln!("❌ Failed to list deleted work items: {e}");
+                    return Err(e);
+                }
+            }
+        }
+        RecycleBinSubCommands::Restore { id, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let id = parse_id(id)?;
+            println!("Restoring work item with id: {id} in project: {project_name}");
+
+            match restore_work_item(&project_name, id).await {
+                Ok(restored) => println!("{}", restored_message(id, &restored).green()),
+                Err(e) => {
+                    eprintln!("❌ Failed to restore work item: {e}");
+                    return Err(e);
+                }
+            }
+        }
+        RecycleBinSubCommands::Destroy { id, project, yes } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            let id = parse_id(id)?;
+
+            if !*yes {
+                let prompt_message = format!(
+                    "Are you sure you want to permanently destroy work item '{id}'? This cannot be undone"
+                );
+                if !Confirm::new()
+                    .with_prompt(prompt_message)
+                    .default(false)
+                    .interact()?
+                {
+                    println!("Destroy operation cancelled.");
+                    return Ok(());
+                }
+            } else {
+                println!("Proceeding with destroy operation (confirmation skipped)...");
+            }
+
+            match destroy_work_item(&project_name, id).await {
+                Ok(_) => println!("{}", "✅ Work item destroyed permanently".green()),
+                Err(e) => {
+                    eprintln!("❌ Failed to destroy work item: {e}");
+                    return Err(e);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_id(id: &str) -> Result<i32> {
+    id.parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))
+}
+
+pub async fn list_deleted_work_items(project: &str) -> Result<Vec<WorkItemDeleteReference>> {
+    let creds = get_credentials()?;
+    let client = create_client()?.recyclebin_client();
+
+    let ids: Vec<String> = client
+        .get_deleted_work_item_shallow_references(&creds.organization, project)
+        .await?
+        .value
+        .iter()
+        .filter_map(|reference| reference.id)
+        .map(|id| id.to_string())
+        .collect();
+
+    let mut deleted = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(DELETED_BATCH_SIZE) {
+        let batch = client
+            .get_deleted_work_items(&creds.organization, chunk.join(","), project)
+            .await?;
+        deleted.extend(batch.value);
+    }
+
+    // Most recently deleted first
+    deleted.sort_by(|a, b| b.deleted_date.cmp(&a.deleted_date));
+    Ok(deleted)
+}
+
+/// Shortens `text` to `width` characters, ending it with '...' when it is cut
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() > width {
+        format!("{}...", text.chars().take(width - 3).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Formats a deleted work item as a row of the recycle bin table
+fn deleted_row(item: &WorkItemDeleteReference) -> String {
+    format!(
+        "{:<10} {:<15} {:<40} {:<25} {:<20}",
+        item.id.map(|id| id.to_string()).unwrap_or_default(),
+        item.type_.as_deref().unwrap_or_default(),
+        truncate(item.name.as_deref().unwrap_or_default(), 40),
+        truncate(item.deleted_by.as_deref().unwrap_or_default(), 25),
+        item.deleted_date
+            .as_deref()
+            .map(|d| d.chars().take(19).collect::<String>())
+            .unwrap_or_default()
+    )
+}
+
+/// Describes a restored work item, e.g. "✅ Restored Bug 42: Crash on save"
+fn restored_message(id: i32, restored: &WorkItemDeleteReference) -> String {
+    match (restored.type_.as_deref(), restored.name.as_deref()) {
+        (Some(work_item_type), Some(name)) => {
+            format!("✅ Restored {work_item_type} {id}: {name}")
+        }
+        _ => format!("✅ Restored work item {id}"),
+    }
+}
+
+fn display_deleted_work_items(deleted: &[WorkItemDeleteReference]) {
+    if deleted.is_empty() {
+        println!("The recycle bin is empty.");
+        return;
+    }
+
+    println!();
+    println!(
+        "{:<10} {:<15} {:<40} {:<25} {:<20}",
+        "ID", "Type", "Title", "Deleted By", "Deleted Date"
+    );
+    println!("{}", "-".repeat(114));
+
+    for item in deleted {
+        println!("{}", deleted_row(item));
+    }
+
+    println!();
+    println!("Total: {} deleted work items", deleted.len());
+}
+
+pub async fn restore_work_item(project: &str, id: i32) -> Result<WorkItemDeleteReference> {
+    let creds = get_credentials()?;
+    let restored = create_client()?
+        .recyclebin_client()
+        .restore_work_item(
+            creds.organization,
+            WorkItemDeleteUpdate {
+                is_deleted: Some(false),
+            },
+            id,
+            project,
+        )
+        .await?;
+    Ok(restored.work_item_delete_reference)
+}
+
+pub async fn destroy_work_item(project: &str, id: i32) -> Result<()> {
+    let creds = get_credentials()?;
+    create_client()?
+        .recyclebin_client()
+        .destroy_work_item(creds.organization, id, project)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_id() {
+        assert_eq!(parse_id("42").unwrap(), 42);
+        assert!(parse_id("abc").is_err());
+        assert!(parse_id("").is_err());
+    }
+
+    #[test]
+    fn test_deleted_row() {
+        let item: WorkItemDeleteReference = serde_json::from_value(json!({
+            "id": 42,
+            "type": "Bug",
+            "name": "A very long title that does not fit into the title column",
+            "deletedBy": "Jane Doe <jane@contoso.com>",
+            "deletedDate": "2026-10-05T12:34:56.789Z"
+        }))
+        .unwrap();
+        let row = deleted_row(&item);
+        assert!(
+            row.starts_with("42         Bug             A very long title that does not fit i...")
+        );
+        assert!(row.contains("Jane Doe <jane@contoso..."));
+        assert!(row.ends_with("2026-10-05T12:34:56 "));
+    }
+
+    #[test]
+    fn test_restored_message() {
+        let restored: WorkItemDeleteReference =
+            serde_json::from_value(json!({ "type": "Bug", "name": "Crash on save" })).unwrap();
+        assert_eq!(
+            restored_message(42, &restored),
+            "✅ Restored Bug 42: Crash on save"
+        );
+        assert_eq!(
+            restored_message(42, &WorkItemDeleteReference::default()),
+            "✅ Restored work item 42"
+        );
+    }
+}
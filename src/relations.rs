@@ -0,0 +1,553 @@
+use crate::auth::get_credentials;
+use crate::boards;
+use crate::pipelines;
+use crate::pr;
+use crate::query::format_field_value;
+use crate::repos;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::json_patch_operation::Op;
+use azure_devops_rust_api::wit::models::{JsonPatchOperation, WorkItemRelation};
+use serde_json::json;
+
+/// Fields fetched for related work items
+const RELATED_FIELDS: [&str; 2] = ["System.WorkItemType", "System.Title"];
+
+/// An artifact linked to a work item, decoded from its `vstfs:///` URL
+#[derive(Debug, Clone, PartialEq)]
+pub enum Artifact {
+    PullRequest {
+        project_id: String,
+        repository_id: String,
+        id: i32,
+    },
+    Commit {
+        project_id: String,
+        repository_id: String,
+        sha: String,
+    },
+    Branch {
+        project_id: String,
+        repository_id: String,
+        name: String,
+    },
+    Build {
+        id: i64,
+    },
+}
+
+/// Percent-encodes a part of an artifact URL, keeping only unreserved characters
+fn encode_segment(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Decodes the `%XX` escapes of an artifact URL, keeping malformed ones as they are
+fn decode_segment(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Decodes an artifact link such as `vstfs:///Git/PullRequestId/{project}%2F{repo}%2F{id}`
+pub fn parse_artifact_url(url: &str) -> Option<Artifact> {
+    let rest = url.strip_prefix("vstfs:///")?;
+    let mut parts = rest.splitn(3, '/');
+    let (tool, kind, id) = (parts.next()?, parts.next()?, parts.next()?);
+    let id = decode_segment(id);
+
+    let git_parts = || {
+        let mut parts = id.splitn(3, '/');
+        Some((
+            parts.next()?.to_string(),
+            parts.next()?.to_string(),
+            parts.next()?.to_string(),
+        ))
+    };
+    match (tool, kind) {
+        ("Git", "PullRequestId") => {
+            let (project_id, repository_id, id) = git_parts()?;
+            Some(Artifact::PullRequest {
+                project_id,
+                repository_id,
+                id: id.parse().ok()?,
+            })
+        }
+        ("Git", "Commit") => {
+            let (project_id, repository_id, sha) = git_parts()?;
+            Some(Artifact::Commit {
+                project_id,
+                repository_id,
+                sha,
+            })
+        }
+        ("Git", "Ref") => {
+            let (project_id, repository_id, name) = git_parts()?;
+            Some(Artifact::Branch {
+                project_id,
+                repository_id,
+                name: name.strip_prefix("GB")?.to_string(),
+            })
+        }
+        ("Build", "Build") => Some(Artifact::Build {
+            id: id.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+/// Builds the `vstfs:///` URL that identifies an artifact in a work item link
+pub fn artifact_url(artifact: &Artifact) -> String {
+    match artifact {
+        Artifact::PullRequest {
+            project_id,
+            repository_id,
+            id,
+        } => format!("vstfs:///Git/PullRequestId/{project_id}%2F{repository_id}%2F{id}"),
+        Artifact::Commit {
+            project_id,
+            repository_id,
+            sha,
+        } => format!("vstfs:///Git/Commit/{project_id}%2F{repository_id}%2F{sha}"),
+        Artifact::Branch {
+            project_id,
+            repository_id,
+            name,
+        } => format!(
+            "vstfs:///Git/Ref/{project_id}%2F{repository_id}%2F{}",
+            encode_segment(&format!("GB{name}"))
+        ),
+        Artifact::Build { id } => format!("vstfs:///Build/Build/{id}"),
+    }
+}
+
+/// Name of the link type Azure DevOps uses for an artifact
+fn artifact_link_name(artifact: &Artifact) -> &'static str {
+    match artifact {
+        Artifact::PullRequest { .. } => "Pull Request",
+        Artifact::Commit { .. } => "Fixed in Commit",
+        Artifact::Branch { .. } => "Branch",
+        Artifact::Build { .. } => "Build",
+    }
+}
+
+/// An artifact named on the command line by repository, e.g. `shop-api!42`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArtifactRef {
+    PullRequest { repo: String, id: i32 },
+    Commit { repo: String, sha: String },
+    Branch { repo: String, name: String },
+}
+
+fn split_ref<'a>(
+    value: &'a str,
+    separator: char,
+    example: &str,
+) -> Result<(&'a str, &'a str), String> {
+    match value.split_once(separator) {
+        Some((repo, rest)) if !repo.trim().is_empty() && !rest.trim().is_empty() => {
+            Ok((repo.trim(), rest.trim()))
+        }
+        _ => Err(format!("Invalid value '{value}', expected {example}")),
+    }
+}
+
+/// Parses `--pr <repo>!<pr-id>`
+pub fn parse_pull_request_ref(value: &str) -> Result<ArtifactRef, String> {
+    let (repo, id) = split_ref(value, '!', "<repo>!<pr-id>")?;
+    let id = id
+        .parse()
+        .map_err(|_| format!("Invalid pull request ID '{id}', must be a number"))?;
+    Ok(ArtifactRef::PullRequest {
+        repo: repo.to_string(),
+        id,
+    })
+}
+
+/// Parses `--commit <repo>@<sha>`
+pub fn parse_commit_ref(value: &str) -> Result<ArtifactRef, String> {
+    let (repo, sha) = split_ref(value, '@', "<repo>@<sha>")?;
+    if !sha.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid commit SHA '{sha}'"));
+    }
+    Ok(ArtifactRef::Commit {
+        repo: repo.to_string(),
+        sha: sha.to_string(),
+    })
+}
+
+/// Parses `--branch <repo>:<branch>`
+pub fn parse_branch_ref(value: &str) -> Result<ArtifactRef, String> {
+    let (repo, name) = split_ref(value, ':', "<repo>:<branch>")?;
+    Ok(ArtifactRef::Branch {
+        repo: repo.to_string(),
+        name: name.trim_start_matches("refs/heads/").to_string(),
+    })
+}
+
+/// Looks up the repository (and the commit, pull request or branch) an artifact
+/// reference points to, returning the IDs needed to link it
+pub async fn resolve_artifact(project: &str, artifact: &ArtifactRef) -> Result<Artifact> {
+    let repo_name = match artifact {
+        ArtifactRef::PullRequest { repo, .. }
+        | ArtifactRef::Commit { repo, .. }
+        | ArtifactRef::Branch { repo, .. } => repo,
+    };
+    let repo = repos::get_repo(project, repo_name).await?;
+    let project_id = repo
+        .project
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Project ID of repository '{}' is missing", repo.name))?;
+    let creds = get_credentials()?;
+
+    match artifact {
+        ArtifactRef::PullRequest { id, .. } => {
+            let pull_request = pr::create_client()?
+                .pull_requests_client()
+                .get_pull_request_by_id(&creds.organization, *id, project)
+                .await?;
+            if pull_request.repository.id != repo.id {
+                return Err(anyhow!(
+                    "Pull request !{} is not in repository '{}'",
+                    id,
+                    repo.name
+                ));
+            }
+            Ok(Artifact::PullRequest {
+                project_id,
+                repository_id: repo.id,
+                id: *id,
+            })
+        }
+        ArtifactRef::Commit { sha, .. } => {
+            // Links need the full SHA, so abbreviated ones are expanded
+            let commit = pr::create_client()?
+                .commits_client()
+                .get(&creds.organization, sha, &repo.id, project)
+                .await?;
+            Ok(Artifact::Commit {
+                project_id,
+                repository_id: repo.id,
+                sha: commit
+                    .git_commit_ref
+                    .commit_id
+                    .unwrap_or_else(|| sha.clone()),
+            })
+        }
+        ArtifactRef::Branch { name, .. } => {
+            if repos::get_branch_head(project, &repo, name)
+                .await?
+                .is_none()
+            {
+                return Err(anyhow!(
+                    "Branch '{}' not found in repository '{}'",
+                    name,
+                    repo.name
+                ));
+            }
+            Ok(Artifact::Branch {
+                project_id,
+                repository_id: repo.id,
+                name: name.clone(),
+            })
+        }
+    }
+}
+
+/// Adds artifact links to a work item in a single update
+pub async fn link_artifacts(project: &str, id: i32, artifacts: &[Artifact]) -> Result<()> {
+    let patch_operations: Vec<JsonPatchOperation> = artifacts
+        .iter()
+        .map(|artifact| JsonPatchOperation {
+            from: None,
+            op: Some(Op::Add),
+            path: Some("/relations/-".to_owned()),
+            value: Some(json!({
+                "rel": "ArtifactLink",
+                "url": artifact_url(artifact),
+                "attributes": { "name": artifact_link_name(artifact) }
+            })),
+        })
+        .collect();
+
+    let creds = get_credentials()?;
+    boards::create_client()?
+        .work_items_client()
+        .update(creds.organization, patch_operations, id, project)
+        .await?;
+    Ok(())
+}
+
+/// A relation of a work item, ready to be displayed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relation {
+    pub kind: String,
+    pub target: String,
+    pub title: String,
+}
+
+/// Describes a work item link type, e.g. 'Parent' for the reverse hierarchy link
+fn link_kind(relation: &WorkItemRelation) -> String {
+    match relation.link.rel.as_str() {
+        "System.LinkTypes.Hierarchy-Reverse" => "Parent".to_string(),
+        "System.LinkTypes.Hierarchy-Forward" => "Child".to_string(),
+        "System.LinkTypes.Related" => "Related".to_string(),
+        "System.LinkTypes.Dependency-Reverse" => "Predecessor".to_string(),
+        "System.LinkTypes.Dependency-Forward" => "Successor".to_string(),
+        "Hyperlink" => "Hyperlink".to_string(),
+        "AttachedFile" => "Attachment".to_string(),
+        rel => relation
+            .link
+            .attributes
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(rel)
+            .to_string(),
+    }
+}
+
+/// Order in which relations are listed: hierarchy first, then other links and artifacts
+fn kind_rank(kind: &str) -> usize {
+    match kind {
+        "Parent" => 0,
+        "Child" => 1,
+        "Pull Request" => 3,
+        "Commit" => 4,
+        "Branch" => 5,
+        "Build" => 6,
+        "Hyperlink" | "Attachment" => 7,
+        _ => 2,
+    }
+}
+
+fn work_item_id(url: &str) -> Option<i32> {
+    url.rsplit('/').next()?.parse().ok()
+}
+
+async fn artifact_relation(project: &str, artifact: &Artifact) -> Result<Relation> {
+    let creds = get_credentials()?;
+    Ok(match artifact {
+        Artifact::PullRequest { project_id, id, .. } => {
+            let pull_request = pr::create_client()?
+                .pull_requests_client()
+                .get_pull_request_by_id(&creds.organization, *id, project_id)
+                .await?;
+            Relation {
+                kind: "Pull Request".to_string(),
+                target: format!("!{id}"),
+                title: format!(
+                    "{} ({})",
+                    pull_request.title.as_deref().unwrap_or_default(),
+                    pull_request.repository.name
+                ),
+            }
+        }
+        Artifact::Commit {
+            project_id,
+            repository_id,
+            sha,
+        } => {
+            let commit = pr::create_client()?
+                .commits_client()
+                .get(&creds.organization, sha, repository_id, project_id)
+                .await?;
+            Relation {
+                kind: "Commit".to_string(),
+                target: sha.chars().take(8).collect(),
+                title: commit
+                    .git_commit_ref
+                    .comment
+                    .as_deref()
+                    .and_then(|c| c.lines().next())
+                    .unwrap_or_default()
+                    .to_string(),
+            }
+        }
+        Artifact::Branch { name, .. } => Relation {
+            kind: "Branch".to_string(),
+            target: name.clone(),
+            title: String::new(),
+        },
+        Artifact::Build { id } => {
+            let build = pipelines::get_build_run(project, *id).await?;
+            Relation {
+                kind: "Build".to_string(),
+                target: format!("#{id}"),
+                title: format!("{} {} ({})", build.pipeline, build.number, build.status),
+            }
+        }
+    })
+}
+
+/// Resolves the relations of a work item, looking up the titles of the linked
+/// work items, pull requests, commits and builds
+pub async fn resolve_relations(project: &str, relations: &[WorkItemRelation]) -> Vec<Relation> {
+    let ids: Vec<i32> = relations
+        .iter()
+        .filter(|r| r.link.rel.starts_with("System.LinkTypes."))
+        .filter_map(|r| work_item_id(&r.link.url))
+        .collect();
+    let work_items = match (ids.is_empty(), get_credentials(), boards::create_client()) {
+        (false, Ok(creds), Ok(client)) => {
+            let fields = RELATED_FIELDS.map(String::from);
+            boards::get_work_items_details(&client, &creds.organization, project, &ids, &fields)
+                .await
+        }
+        _ => Vec::new(),
+    };
+
+    let mut resolved = Vec::with_capacity(relations.len());
+    for relation in relations {
+        let link = &relation.link;
+        let kind = link_kind(relation);
+        let entry = if link.rel == "ArtifactLink" {
+            match parse_artifact_url(&link.url) {
+                Some(artifact) => match artifact_relation(project, &artifact).await {
+                    Ok(entry) => entry,
+                    Err(e) => Relation {
+                        kind,
+                        target: link.url.clone(),
+                        title: format!("(unavailable: {e})"),
+                    },
+                },
+                None => Relation {
+                    kind,
+                    target: link.url.clone(),
+                    title: String::new(),
+                },
+            }
+        } else if let Some(id) = work_item_id(&link.url).filter(|_| link.rel != "AttachedFile") {
+            let field = |name: &str| {
+                work_items
+                    .iter()
+                    .find(|wi| wi.id == id)
+                    .and_then(|wi| wi.fields.get(name))
+                    .map(format_field_value)
+                    .unwrap_or_default()
+            };
+            Relation {
+                kind,
+                target: format!("#{id}"),
+                title: format!(
+                    "{}: {}",
+                    field("System.WorkItemType"),
+                    field("System.Title")
+                ),
+            }
+        } else {
+            let name = link
+                .attributes
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            Relation {
+                kind,
+                target: if name.is_empty() {
+                    link.url.clone()
+                } else {
+                    name.to_string()
+                },
+                title: String::new(),
+            }
+        };
+        resolved.push(entry);
+    }
+
+    resolved.sort_by_key(|r| kind_rank(&r.kind));
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_artifact_url() {
+        assert_eq!(
+            parse_artifact_url("vstfs:///Git/PullRequestId/p1%2Fr1%2F42"),
+            Some(Artifact::PullRequest {
+                project_id: "p1".to_string(),
+                repository_id: "r1".to_string(),
+                id: 42
+            })
+        );
+        assert_eq!(
+            parse_artifact_url("vstfs:///Git/Ref/p1%2Fr1%2FGBfeature%2Fcheckout"),
+            Some(Artifact::Branch {
+                project_id: "p1".to_string(),
+                repository_id: "r1".to_string(),
+                name: "feature/checkout".to_string()
+            })
+        );
+        assert_eq!(
+            parse_artifact_url("vstfs:///Build/Build/1234"),
+            Some(Artifact::Build { id: 1234 })
+        );
+        assert_eq!(parse_artifact_url("https://example.com"), None);
+    }
+
+    #[test]
+    fn test_artifact_refs_round_trip() {
+        assert_eq!(
+            parse_pull_request_ref("shop-api!42"),
+            Ok(ArtifactRef::PullRequest {
+                repo: "shop-api".to_string(),
+                id: 42
+            })
+        );
+        assert!(parse_pull_request_ref("shop-api!abc").is_err());
+        assert!(parse_commit_ref("shop-api@not-a-sha").is_err());
+        assert!(parse_branch_ref("feature/x").is_err());
+
+        let branch = Artifact::Branch {
+            project_id: "p1".to_string(),
+            repository_id: "r1".to_string(),
+            name: "feature/checkout".to_string(),
+        };
+        assert_eq!(
+            artifact_url(&branch),
+            "vstfs:///Git/Ref/p1%2Fr1%2FGBfeature%2Fcheckout"
+        );
+        assert_eq!(parse_artifact_url(&artifact_url(&branch)), Some(branch));
+    }
+
+    #[test]
+    fn test_branch_artifact_url_encodes_name() {
+        let branch = Artifact::Branch {
+            project_id: "p1".to_string(),
+            repository_id: "r1".to_string(),
+            name: "users/ann/fix #12 at 100%".to_string(),
+        };
+        assert_eq!(
+            artifact_url(&branch),
+            "vstfs:///Git/Ref/p1%2Fr1%2FGBusers%2Fann%2Ffix%20%2312%20at%20100%25"
+        );
+        assert_eq!(parse_artifact_url(&artifact_url(&branch)), Some(branch));
+        assert_eq!(decode_segment("50%"), "50%");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::auth::get_credentials;
 use crate::pr::{self, PullRequestsSubCommands};
 use crate::project::get_project_or_default;
+use crate::web;
 use anyhow::Result;
 use azure_devops_rust_api::git::{self, models::GitRepositoryCreateOptions, ClientBuilder};
 use clap::Subcommand;
@@ -67,6 +68,9 @@ pub enum ReposSubCommands {
         /// Team project name (optional if default project is set)
         #[clap(short, long)]
         project: Option<String>,
+        /// Open in web browser
+        #[clap(long)]
+        web: bool,
     },
     /// Manage pull requests in repositories
     PR {
@@ -162,8 +166,12 @@ pub async fn handle_command(subcommand: &ReposSubCommands) -> Result<()> {
                 }
             }
         }
-        ReposSubCommands::Show { id, project } => {
+        ReposSubCommands::Show { id, project, web } => {
             let project_name = get_project_or_default(project.as_deref())?;
+            if *web {
+                let url = web::web_url(&[&project_name, "_git", id])?;
+                return web::open_in_browser(url.as_str());
+            }
             match get_repo(&project_name, id).await {
                 Ok(repo) => {
                     display_repo_details(&repo);
@@ -263,6 +271,95 @@ pub async fn get_repo(project: &str, repository_id: &str) -> Result<git::models:
     }
 }
 
+/// Returns the commit a branch of the repository points to, if the branch exists
+pub async fn get_branch_head(
+    project: &str,
+    repo: &git::models::GitRepository,
+    branch: &str,
+) -> Result<Option<String>> {
+    let creds = get_credentials()?;
+    let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
+    let client = ClientBuilder::new(credential).build();
+    let name = format!("refs/heads/{}", branch.trim_start_matches("refs/heads/"));
+    let refs = client
+        .refs_client()
+        .list(creds.organization, &repo.id, project)
+        .filter(name.trim_start_matches("refs/"))
+        .await?
+        .value;
+
+    // The filter matches on prefix, so 'main' would also return 'main-old'
+    Ok(refs
+        .into_iter()
+        .find(|r| r.name == name)
+        .map(|r| r.object_id))
+}
+
+/// Checks that a new branch can be created from the repository's default branch
+/// and returns the commit it would start from
+pub async fn new_branch_start(
+    project: &str,
+    repo: &git::models::GitRepository,
+    branch: &str,
+) -> Result<String> {
+    let default_branch = repo
+        .default_branch
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("Repository '{}' has no default branch", repo.name))?;
+    let head = get_branch_head(project, repo, default_branch)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Default branch '{}' not found", default_branch))?;
+    if get_branch_head(project, repo, branch).await?.is_some() {
+        return Err(anyhow::anyhow!(
+            "Branch '{}' already exists in '{}'",
+            branch,
+            repo.name
+        ));
+    }
+    Ok(head)
+}
+
+/// Creates a branch pointing at the `head` commit, see `new_branch_start`
+pub async fn create_branch(
+    project: &str,
+    repo: &git::models::GitRepository,
+    branch: &str,
+    head: String,
+) -> Result<()> {
+    let creds = get_credentials()?;
+    let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
+    let client = ClientBuilder::new(credential).build();
+    let update = git::models::GitRefUpdate {
+        name: Some(format!(
+            "refs/heads/{}",
+            branch.trim_start_matches("refs/heads/")
+        )),
+        // An all-zero old object ID creates the ref
+        old_object_id: Some("0".repeat(40)),
+        new_object_id: Some(head),
+        ..Default::default()
+    };
+    let results = client
+        .refs_client()
+        .update_refs(creds.organization, vec![update], &repo.id, project)
+        .await?
+        .value;
+
+    match results.first() {
+        Some(result) if result.success == Some(true) => Ok(()),
+        Some(result) => Err(anyhow::anyhow!(
+            "Branch '{}' was not created: {}",
+            branch,
+            result
+                .custom_message
+                .clone()
+                .or_else(|| result.update_status.as_ref().map(|s| format!("{s:?}")))
+                .unwrap_or_else(|| "rejected".to_string())
+        )),
+        None => Err(anyhow::anyhow!("Branch '{}' was not created", branch)),
+    }
+}
+
 async fn delete_repo(project: &str, repository_id: &str, hard_delete: bool) -> Result<()> {
     match get_credentials() {
         Ok(creds) => {
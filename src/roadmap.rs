@@ -0,0 +1,552 @@
+use crate::auth::get_credentials;
+use crate::boards::{self, sanitize_wiql_value};
+use crate::query::{self, format_field_value};
+use crate::sprint;
+use crate::states;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItem;
+use chrono::{Datelike, Local, NaiveDate};
+use clap::ValueEnum;
+use std::collections::{HashMap, HashSet};
+
+/// Fields of the Epics and Features on the roadmap
+const ROADMAP_FIELDS: [&str; 6] = [
+    "System.WorkItemType",
+    "System.Title",
+    "System.State",
+    "System.Parent",
+    "Microsoft.VSTS.Scheduling.StartDate",
+    "Microsoft.VSTS.Scheduling.TargetDate",
+];
+
+/// Fields of the child work items used to compute the progress
+const CHILD_FIELDS: [&str; 3] = ["System.WorkItemType", "System.State", "System.Parent"];
+
+/// Width of the timeline in the chart
+const CHART_WIDTH: usize = 52;
+
+/// Width of the title column in the chart
+const TITLE_WIDTH: usize = 40;
+
+/// Output formats of `boards roadmap`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum RoadmapFormat {
+    Chart,
+    Md,
+}
+
+/// An Epic or Feature on the roadmap, with the progress of its child stories
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoadmapItem {
+    pub id: i32,
+    pub work_item_type: String,
+    pub title: String,
+    /// 0 for top-level items, 1 for Features shown under their Epic
+    pub depth: usize,
+    pub start: Option<NaiveDate>,
+    pub target: Option<NaiveDate>,
+    pub done: usize,
+    pub total: usize,
+}
+
+impl RoadmapItem {
+    fn percent(&self) -> Option<usize> {
+        (self.total > 0).then(|| self.done * 100 / self.total)
+    }
+
+    fn progress(&self) -> String {
+        match self.percent() {
+            Some(percent) => format!("{percent}% ({}/{})", self.done, self.total),
+            None => "-".to_string(),
+        }
+    }
+
+    /// The dates the item spans, falling back to a single day if only one is set
+    fn span(&self) -> Option<(NaiveDate, NaiveDate)> {
+        match (self.start, self.target) {
+            (Some(start), Some(target)) => Some((start.min(target), start.max(target))),
+            (Some(day), None) | (None, Some(day)) => Some((day, day)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Parses `--quarter` such as '2026Q4' into the first and last day of the quarter
+pub fn parse_quarter(quarter: &str) -> Result<(NaiveDate, NaiveDate)> {
+    let invalid = || anyhow!("Invalid quarter '{}', expected e.g. 2026Q4", quarter);
+    let (year, q) = quarter
+        .trim()
+        .to_uppercase()
+        .split_once('Q')
+        .map(|(y, q)| (y.to_string(), q.to_string()))
+        .ok_or_else(invalid)?;
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let q: u32 = q.parse().map_err(|_| invalid())?;
+    if !(1..=4).contains(&q) {
+        return Err(invalid());
+    }
+
+    let start = NaiveDate::from_ymd_opt(year, (q - 1) * 3 + 1, 1).ok_or_else(invalid)?;
+    let next = if q == 4 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, q * 3 + 1, 1)
+    }
+    .ok_or_else(invalid)?;
+    Ok((start, next.pred_opt().ok_or_else(invalid)?))
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() > width {
+        format!(
+            "{}...",
+            value
+                .chars()
+                .take(width.saturating_sub(3))
+                .collect::<String>()
+        )
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders the items as bars on a timeline from `from` to `to`, with the completed
+/// share of each bar filled in
+pub fn render_gantt(
+    items: &[RoadmapItem],
+    from: NaiveDate,
+    to: NaiveDate,
+    width: usize,
+) -> Vec<String> {
+    let days = (to - from).num_days().max(0) + 1;
+    // First day covered by each column of the timeline
+    let column_day =
+        |column: usize| from + chrono::Duration::days(column as i64 * days / width as i64);
+
+    let mut axis = vec![' '; width];
+    let mut month = None;
+    for column in 0..width {
+        let day = column_day(column);
+        if month != Some(day.month()) {
+            month = Some(day.month());
+            let label = day.format("%b").to_string();
+            if column + label.len() <= width
+                && axis[column..column + label.len()].iter().all(|c| *c == ' ')
+            {
+                for (offset, c) in label.chars().enumerate() {
+                    axis[column + offset] = c;
+                }
+            }
+        }
+    }
+
+    let mut lines = vec![
+        format!(
+            "{:<title$} │{}│ Progress",
+            "",
+            axis.iter().collect::<String>(),
+            title = TITLE_WIDTH
+        ),
+        format!(
+            "{}┼{}┼{}",
+            "─".repeat(TITLE_WIDTH + 1),
+            "─".repeat(width),
+            "─".repeat(10)
+        ),
+    ];
+
+    for item in items {
+        let label = format!(
+            "{}{} #{} {}",
+            "  ".repeat(item.depth),
+            item.work_item_type,
+            item.id,
+            item.title
+        );
+        let bar: String = match item.span() {
+            Some((start, end)) => {
+                let columns: Vec<usize> = (0..width)
+                    .filter(|column| {
+                        let first = column_day(*column);
+                        let last = column_day(column + 1)
+                            .pred_opt()
+                            .unwrap_or(first)
+                            .max(first);
+                        first <= end && last >= start
+                    })
+                    .collect();
+                let filled = match item.percent() {
+                    Some(percent) => (columns.len() * percent + 50) / 100,
+                    None => 0,
+                };
+                (0..width)
+                    .map(|column| match columns.iter().position(|c| *c == column) {
+                        Some(index) if index < filled => '█',
+                        Some(_) => '░',
+                        None => ' ',
+                    })
+                    .collect()
+            }
+            None => format!("{:^width$}", "(no dates)"),
+        };
+        lines.push(format!(
+            "{:<title$} │{}│ {}",
+            truncate(&label, TITLE_WIDTH),
+            bar,
+            item.progress(),
+            title = TITLE_WIDTH
+        ));
+    }
+    lines
+}
+
+/// Renders the items as a Markdown table
+pub fn render_markdown(items: &[RoadmapItem]) -> String {
+    let date = |d: Option<NaiveDate>| d.map(|d| d.to_string()).unwrap_or_else(|| "-".to_string());
+    let mut markdown = String::from(
+        "| ID | Type | Title | Start | Target | Progress |\n|---|---|---|---|---|---|\n",
+    );
+    for item in items {
+        markdown.push_str(&format!(
+            "| {} | {} | {}{} | {} | {} | {} |\n",
+            item.id,
+            item.work_item_type,
+            if item.depth > 0 { "↳ " } else { "" },
+            item.title.replace('|', "\\|"),
+            date(item.start),
+            date(item.target),
+            item.progress()
+        ));
+    }
+    markdown
+}
+
+fn field_date(work_item: &WorkItem, field: &str) -> Option<NaiveDate> {
+    work_item
+        .fields
+        .get(field)
+        .and_then(|v| v.as_str())
+        .and_then(sprint::parse_date)
+}
+
+fn field_text(work_item: &WorkItem, field: &str) -> String {
+    work_item
+        .fields
+        .get(field)
+        .map(format_field_value)
+        .unwrap_or_default()
+}
+
+fn parent_id(work_item: &WorkItem) -> Option<i32> {
+    work_item
+        .fields
+        .get("System.Parent")
+        .and_then(|v| v.as_i64())
+        .map(|id| id as i32)
+}
+
+async fn fetch(project: &str, wiql: &str, fields: &[&str]) -> Result<Vec<WorkItem>> {
+    let ids = query::result_ids(&query::execute_query(project, Some(wiql), None).await?);
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let creds = get_credentials()?;
+    let fields: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+    Ok(boards::get_work_items_details(
+        &boards::create_client()?,
+        &creds.organization,
+        project,
+        &ids,
+        &fields,
+    )
+    .await)
+}
+
+/// Parent IDs per children query, like the chunks of `boards::get_work_items_details`
+const PARENTS_PER_QUERY: usize = 200;
+
+/// The WIQL selecting the children of `parents` within the project
+fn children_query(project: &str, parents: &[i32]) -> String {
+    let ids: Vec<String> = parents.iter().map(|id| id.to_string()).collect();
+    format!(
+        "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = '{}' AND [System.Parent] IN ({})",
+        sanitize_wiql_value(project),
+        ids.join(", ")
+    )
+}
+
+async fn fetch_children(project: &str, parents: &[i32]) -> Result<Vec<WorkItem>> {
+    let mut children = Vec::new();
+    for chunk in parents.chunks(PARENTS_PER_QUERY) {
+        let wiql = children_query(project, chunk);
+        children.extend(fetch(project, &wiql, &CHILD_FIELDS).await?);
+    }
+    Ok(children)
+}
+
+/// Loads the state categories of the work item types in `work_items` that aren't known yet
+async fn load_state_categories<'a>(
+    project: &str,
+    categories: &mut HashMap<String, HashMap<String, String>>,
+    work_items: impl Iterator<Item = &'a WorkItem>,
+) -> Result<()> {
+    let types: HashSet<String> = work_items
+        .map(|wi| field_text(wi, "System.WorkItemType"))
+        .filter(|t| !categories.contains_key(t))
+        .collect();
+    for work_item_type in types {
+        let states = states::get_type_states(project, &work_item_type).await?;
+        categories.insert(work_item_type, states.states.into_iter().collect());
+    }
+    Ok(())
+}
+
+/// The category of a work item's state, e.g. "Completed" or "Removed"
+fn state_category(categories: &HashMap<String, HashMap<String, String>>, wi: &WorkItem) -> String {
+    categories
+        .get(&field_text(wi, "System.WorkItemType"))
+        .and_then(|states| states.get(&field_text(wi, "System.State")))
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn is_portfolio_type(work_item_type: &str) -> bool {
+    work_item_type == "Epic" || work_item_type == "Feature"
+}
+
+/// Counts (done, total) of the stories below an item, going through child Features
+fn story_progress(
+    id: i32,
+    children_of: &HashMap<i32, Vec<&WorkItem>>,
+    category: &dyn Fn(&WorkItem) -> String,
+) -> (usize, usize) {
+    let mut counts = (0, 0);
+    for child in children_of.get(&id).into_iter().flatten() {
+        if is_portfolio_type(&field_text(child, "System.WorkItemType")) {
+            let (done, total) = story_progress(child.id, children_of, category);
+            counts.0 += done;
+            counts.1 += total;
+            continue;
+        }
+        match category(child).as_str() {
+            "Removed" => {}
+            "Completed" => {
+                counts.0 += 1;
+                counts.1 += 1;
+            }
+            _ => counts.1 += 1,
+        }
+    }
+    counts
+}
+
+pub async fn show_roadmap(
+    project: &str,
+    area: &str,
+    quarter: Option<&str>,
+    format: RoadmapFormat,
+) -> Result<()> {
+    let range = quarter.map(parse_quarter).transpose()?;
+
+    let wiql = format!(
+        "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = '{}' AND [System.AreaPath] UNDER '{}' AND [System.WorkItemType] IN ('Epic', 'Feature') ORDER BY [Microsoft.VSTS.Scheduling.TargetDate]",
+        sanitize_wiql_value(project),
+        sanitize_wiql_value(area)
+    );
+    // Progress goes to stderr, so the Markdown can be redirected to a file
+    eprintln!("Loading Epics and Features under {area}...");
+    let mut portfolio = fetch(project, &wiql, &ROADMAP_FIELDS).await?;
+
+    // Removed items are left out by the category of their state, whatever it is called
+    let mut categories: HashMap<String, HashMap<String, String>> = HashMap::new();
+    load_state_categories(project, &mut categories, portfolio.iter()).await?;
+    portfolio.retain(|wi| state_category(&categories, wi) != "Removed");
+    if portfolio.is_empty() {
+        println!("No Epics or Features found under '{area}'");
+        return Ok(());
+    }
+
+    // Stories hang off Features, which may themselves be children of the Epics
+    let children = fetch_children(
+        project,
+        &portfolio.iter().map(|wi| wi.id).collect::<Vec<_>>(),
+    )
+    .await?;
+    let known: HashSet<i32> = portfolio.iter().map(|wi| wi.id).collect();
+    let child_features: Vec<i32> = children
+        .iter()
+        .filter(|wi| {
+            is_portfolio_type(&field_text(wi, "System.WorkItemType")) && !known.contains(&wi.id)
+        })
+        .map(|wi| wi.id)
+        .collect();
+    let grandchildren = fetch_children(project, &child_features).await?;
+
+    let mut children_of: HashMap<i32, Vec<&WorkItem>> = HashMap::new();
+    for child in children.iter().chain(&grandchildren) {
+        if let Some(parent) = parent_id(child) {
+            children_of.entry(parent).or_default().push(child);
+        }
+    }
+
+    // Stories count as done when their state is in the Completed category
+    load_state_categories(
+        project,
+        &mut categories,
+        children.iter().chain(&grandchildren),
+    )
+    .await?;
+    let category = |wi: &WorkItem| state_category(&categories, wi);
+
+    let mut items: Vec<RoadmapItem> = portfolio
+        .iter()
+        .map(|wi| {
+            let (done, total) = story_progress(wi.id, &children_of, &category);
+            RoadmapItem {
+                id: wi.id,
+                work_item_type: field_text(wi, "System.WorkItemType"),
+                title: field_text(wi, "System.Title"),
+                depth: 0,
+                start: field_date(wi, "Microsoft.VSTS.Scheduling.StartDate"),
+                target: field_date(wi, "Microsoft.VSTS.Scheduling.TargetDate"),
+                done,
+                total,
+            }
+        })
+        .filter(|item| match (range, item.span()) {
+            (Some((from, to)), Some((start, end))) => start <= to && end >= from,
+            (Some(_), None) => false,
+            (None, _) => true,
+        })
+        .collect();
+    if items.is_empty() {
+        println!(
+            "No Epics or Features planned in {}",
+            quarter.unwrap_or_default()
+        );
+        return Ok(());
+    }
+
+    // Features are listed under their Epic when it is on the roadmap too
+    let parents: HashMap<i32, i32> = portfolio
+        .iter()
+        .filter_map(|wi| Some((wi.id, parent_id(wi)?)))
+        .collect();
+    let shown: HashSet<i32> = items.iter().map(|item| item.id).collect();
+    let (nested, top): (Vec<RoadmapItem>, Vec<RoadmapItem>) = items.drain(..).partition(|item| {
+        item.work_item_type == "Feature"
+            && parents
+                .get(&item.id)
+                .is_some_and(|parent| shown.contains(parent))
+    });
+    for item in top {
+        let id = item.id;
+        items.push(item);
+        for mut feature in nested
+            .iter()
+            .filter(|f| parents.get(&f.id) == Some(&id))
+            .cloned()
+        {
+            feature.depth = 1;
+            items.push(feature);
+        }
+    }
+
+    match format {
+        RoadmapFormat::Md => {
+            println!(
+                "# Roadmap: {area}{}\n",
+                quarter.map(|q| format!(" ({q})")).unwrap_or_default()
+            );
+            print!("{}", render_markdown(&items));
+        }
+        RoadmapFormat::Chart => {
+            let spans: Vec<(NaiveDate, NaiveDate)> =
+                items.iter().filter_map(|i| i.span()).collect();
+            let (from, to) = range.unwrap_or_else(|| {
+                let today = Local::now().date_naive();
+                (
+                    spans.iter().map(|s| s.0).min().unwrap_or(today),
+                    spans.iter().map(|s| s.1).max().unwrap_or(today),
+                )
+            });
+            println!();
+            println!("🗺️  Roadmap: {area} {from} → {to}");
+            println!();
+            for line in render_gantt(&items, from, to, CHART_WIDTH) {
+                println!("{line}");
+            }
+            println!();
+            println!("█ completed   ░ remaining (share of child stories done)");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn item(
+        id: i32,
+        start: Option<NaiveDate>,
+        target: Option<NaiveDate>,
+        done: usize,
+        total: usize,
+    ) -> RoadmapItem {
+        RoadmapItem {
+            id,
+            work_item_type: "Feature".to_string(),
+            title: "Guest checkout".to_string(),
+            depth: 0,
+            start,
+            target,
+            done,
+            total,
+        }
+    }
+
+    #[test]
+    fn test_children_query_filters_by_project() {
+        assert_eq!(
+            children_query("O'Brien", &[1, 2]),
+            "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = 'O''Brien' AND [System.Parent] IN (1, 2)"
+        );
+    }
+
+    #[test]
+    fn test_parse_quarter() {
+        assert_eq!(
+            parse_quarter("2026Q4").unwrap(),
+            (date(2026, 10, 1), date(2026, 12, 31))
+        );
+        assert_eq!(
+            parse_quarter("2027q1").unwrap(),
+            (date(2027, 1, 1), date(2027, 3, 31))
+        );
+        assert!(parse_quarter("2026Q5").is_err());
+        assert!(parse_quarter("Q4").is_err());
+    }
+
+    #[test]
+    fn test_render_gantt_fills_completed_share() {
+        let items = vec![
+            item(7, Some(date(2026, 10, 1)), Some(date(2026, 10, 10)), 1, 2),
+            item(8, None, None, 0, 0),
+        ];
+        let lines = render_gantt(&items, date(2026, 10, 1), date(2026, 10, 20), 4);
+        assert_eq!(lines[0], format!("{:<40} │Oct │ Progress", ""));
+        assert!(lines[2].ends_with("│█░  │ 50% (1/2)"));
+        assert!(lines[3].ends_with("│(no dates)│ -"));
+
+        assert_eq!(
+            render_markdown(&items[..1]),
+            "| ID | Type | Title | Start | Target | Progress |\n|---|---|---|---|---|---|\n\
+             | 7 | Feature | Guest checkout | 2026-10-01 | 2026-10-10 | 50% (1/2) |\n"
+        );
+    }
+}
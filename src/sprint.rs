@@ -0,0 +1,487 @@
+use crate::auth::get_credentials;
+use crate::boards;
+use crate::projects;
+use crate::query::format_field_value;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItem;
+use azure_devops_rust_api::work::models::team_iteration_attributes::TimeFrame;
+use azure_devops_rust_api::work::{self, models, ClientBuilder};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, Weekday};
+use colored::Colorize;
+use std::collections::BTreeMap;
+
+/// Fields needed to render the sprint view
+const SPRINT_FIELDS: [&str; 5] = [
+    "System.Title",
+    "System.State",
+    "System.WorkItemType",
+    "System.AssignedTo",
+    "Microsoft.VSTS.Scheduling.RemainingWork",
+];
+
+pub fn create_client() -> Result<work::Client> {
+    let creds = get_credentials()?;
+    let credential = azure_devops_rust_api::Credential::Pat(creds.pat);
+    Ok(ClientBuilder::new(credential).build())
+}
+
+/// Returns the team to use, falling back to the project's default team
+pub async fn team_or_default(project: &str, team: Option<&str>) -> Result<String> {
+    match team {
+        Some(team) => Ok(team.to_string()),
+        None => projects::get_default_team(project).await,
+    }
+}
+
+/// Converts an API timestamp (seconds) into a calendar date.
+///
+/// Date-only values such as iteration dates are stored as midnight UTC and keep their
+/// UTC date. Any other instant, like a revision time or a date picked in the web UI
+/// (local midnight stored as UTC), is converted to the local timezone.
+pub fn to_date(timestamp: Option<i64>) -> Option<NaiveDate> {
+    let utc = DateTime::from_timestamp(timestamp?, 0)?;
+    if utc.time() == NaiveTime::MIN {
+        Some(utc.date_naive())
+    } else {
+        Some(utc.with_timezone(&Local).date_naive())
+    }
+}
+
+/// Converts an RFC 3339 field value, e.g. `System.ChangedDate`, into a calendar date
+/// following the same rules as [`to_date`]
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    to_date(Some(DateTime::parse_from_rfc3339(value).ok()?.timestamp()))
+}
+
+/// Start and finish dates of an iteration, if it is scheduled
+pub fn iteration_dates(
+    iteration: &models::TeamSettingsIteration,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let attributes = iteration.attributes.as_ref()?;
+    let start = to_date(attributes.start_date.map(|d| d.unix_timestamp()))?;
+    let finish = to_date(attributes.finish_date.map(|d| d.unix_timestamp()))?;
+    Some((start, finish))
+}
+
+/// Counts the working days (Monday to Friday) in `from..=to` that are not days off
+pub fn working_days(from: NaiveDate, to: NaiveDate, days_off: &[(NaiveDate, NaiveDate)]) -> i64 {
+    from.iter_days()
+        .take_while(|day| *day <= to)
+        .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+        .filter(|day| {
+            !days_off
+                .iter()
+                .any(|(start, end)| day >= start && day <= end)
+        })
+        .count() as i64
+}
+
+fn date_ranges(ranges: &[models::DateRange]) -> Vec<(NaiveDate, NaiveDate)> {
+    ranges
+        .iter()
+        .filter_map(|range| {
+            let start = to_date(range.start.map(|d| d.unix_timestamp()))?;
+            let end = to_date(range.end.map(|d| d.unix_timestamp()))?;
+            Some((start, end))
+        })
+        .collect()
+}
+
+/// Picks an iteration by 'current', 'next', or its path or name
+fn select_iteration<'a>(
+    iterations: &'a [models::TeamSettingsIteration],
+    selector: &str,
+) -> Option<&'a models::TeamSettingsIteration> {
+    let time_frame = |it: &models::TeamSettingsIteration| {
+        it.attributes.as_ref().and_then(|a| a.time_frame.clone())
+    };
+
+    match selector.to_lowercase().as_str() {
+        "current" | "@current" => iterations
+            .iter()
+            .find(|it| time_frame(it) == Some(TimeFrame::Current)),
+        "next" => iterations
+            .iter()
+            .filter(|it| time_frame(it) == Some(TimeFrame::Future))
+            .min_by_key(|it| iteration_dates(it).map(|(start, _)| start)),
+        _ => {
+            let selector = selector.trim_matches('\\').to_lowercase();
+            iterations.iter().find(|it| {
+                it.path
+                    .as_deref()
+                    .map(|p| p.trim_matches('\\').to_lowercase() == selector)
+                    .unwrap_or(false)
+                    || it
+                        .name
+                        .as_deref()
+                        .map(|n| n.to_lowercase() == selector)
+                        .unwrap_or(false)
+            })
+        }
+    }
+}
+
+/// Finds a team iteration by 'current', 'next', or its path or name
+pub async fn resolve_iteration(
+    project: &str,
+    team: &str,
+    selector: &str,
+) -> Result<models::TeamSettingsIteration> {
+    let creds = get_credentials()?;
+    let iterations = create_client()?
+        .iterations_client()
+        .list(creds.organization, project, team)
+        .await?
+        .value;
+
+    if iterations.is_empty() {
+        return Err(anyhow!("Team '{}' has no iterations", team));
+    }
+
+    select_iteration(&iterations, selector)
+        .cloned()
+        .ok_or_else(|| match selector.to_lowercase().as_str() {
+            "current" | "@current" => anyhow!("Team '{}' has no current iteration", team),
+            "next" => anyhow!("Team '{}' has no upcoming iteration", team),
+            _ => anyhow!("Iteration '{}' not found for team '{}'", selector, team),
+        })
+}
+
+/// Returns the IDs of all work items planned in an iteration
+pub async fn get_iteration_work_item_ids(
+    project: &str,
+    team: &str,
+    iteration_id: &str,
+) -> Result<Vec<i32>> {
+    let creds = get_credentials()?;
+    let iteration_work_items = create_client()?
+        .iterations_client()
+        .get_iteration_work_items(creds.organization, project, iteration_id, team)
+        .await?;
+
+    let mut ids = Vec::new();
+    for link in &iteration_work_items.work_item_relations {
+        for reference in [&link.source, &link.target].into_iter().flatten() {
+            if let Some(id) = reference.id {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+    }
+    Ok(ids)
+}
+
+pub fn remaining_work(work_item: &WorkItem) -> f64 {
+    work_item
+        .fields
+        .get("Microsoft.VSTS.Scheduling.RemainingWork")
+        .and_then(serde_json::Value::as_f64)
+        .unwrap_or(0.0)
+}
+
+pub fn assignee(work_item: &WorkItem) -> String {
+    work_item
+        .fields
+        .get("System.AssignedTo")
+        .map(format_field_value)
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Unassigned".to_string())
+}
+
+pub fn field_str<'a>(work_item: &'a WorkItem, field: &str) -> &'a str {
+    work_item
+        .fields
+        .get(field)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+}
+
+/// A team member's capacity for the rest of the iteration
+pub struct MemberCapacity {
+    pub name: String,
+    pub capacity_per_day: f64,
+    /// Activities with capacity, with the hours per day for each
+    pub activities: Vec<(String, f64)>,
+    pub days_off: Vec<(NaiveDate, NaiveDate)>,
+}
+
+pub async fn get_member_capacities(
+    project: &str,
+    team: &str,
+    iteration_id: &str,
+) -> Result<(Vec<MemberCapacity>, Vec<(NaiveDate, NaiveDate)>)> {
+    let creds = get_credentials()?;
+    let client = create_client()?;
+
+    let capacity = client
+        .capacities_client()
+        .get_capacities_with_identity_ref_and_totals(
+            &creds.organization,
+            project,
+            iteration_id,
+            team,
+        )
+        .await?;
+
+    let team_days_off = client
+        .teamdaysoff_client()
+        .get(&creds.organization, project, iteration_id, team)
+        .await
+        .map(|days_off| date_ranges(&days_off.days_off))
+        .unwrap_or_default();
+
+    let members = capacity
+        .team_members
+        .iter()
+        .map(|member| MemberCapacity {
+            name: member
+                .team_member
+                .as_ref()
+                .and_then(|m| m.graph_subject_base.display_name.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            capacity_per_day: member
+                .capacity_contract_base
+                .activities
+                .iter()
+                .filter_map(|a| a.capacity_per_day)
+                .map(f64::from)
+                .sum(),
+            activities: member
+                .capacity_contract_base
+                .activities
+                .iter()
+                .filter(|a| a.capacity_per_day.unwrap_or(0.0) > 0.0)
+                .map(|a| {
+                    (
+                        a.name
+                            .as_deref()
+                            .filter(|n| !n.is_empty())
+                            .unwrap_or("Unassigned")
+                            .to_string(),
+                        f64::from(a.capacity_per_day.unwrap_or(0.0)),
+                    )
+                })
+                .collect(),
+            days_off: date_ranges(&member.capacity_contract_base.days_off),
+        })
+        .collect();
+
+    Ok((members, team_days_off))
+}
+
+pub fn display_sprint_header(
+    team: &str,
+    iteration: &models::TeamSettingsIteration,
+    today: NaiveDate,
+    team_days_off: &[(NaiveDate, NaiveDate)],
+) -> Option<NaiveDate> {
+    println!(
+        "🏃 Sprint: {} ({})",
+        iteration.name.as_deref().unwrap_or("Unnamed").bold(),
+        team
+    );
+    if let Some(path) = &iteration.path {
+        println!("📂 Path: {path}");
+    }
+
+    match iteration_dates(iteration) {
+        Some((start, finish)) => {
+            println!("📅 Dates: {start} → {finish}");
+            let from = if today > start { today } else { start };
+            let remaining = if today > finish {
+                0
+            } else {
+                working_days(from, finish, team_days_off)
+            };
+            println!("⏳ Working days remaining: {remaining}");
+            Some(from)
+        }
+        None => {
+            println!("📅 Dates: not scheduled");
+            None
+        }
+    }
+}
+
+fn display_work_items_by_state(work_items: &[WorkItem]) {
+    let mut by_state: BTreeMap<&str, Vec<&WorkItem>> = BTreeMap::new();
+    for work_item in work_items {
+        by_state
+            .entry(field_str(work_item, "System.State"))
+            .or_default()
+            .push(work_item);
+    }
+
+    for (state, items) in &by_state {
+        let remaining: f64 = items.iter().map(|wi| remaining_work(wi)).sum();
+        println!();
+        println!(
+            "{} ({} items, {} h remaining)",
+            state.bold(),
+            items.len(),
+            remaining
+        );
+        println!("{}", "-".repeat(80));
+        for work_item in items {
+            let title = field_str(work_item, "System.Title");
+            let truncated_title = if title.chars().count() > 40 {
+                format!("{}...", title.chars().take(37).collect::<String>())
+            } else {
+                title.to_string()
+            };
+            println!(
+                "{:<8} {:<12} {:<40} {}",
+                work_item.id,
+                field_str(work_item, "System.WorkItemType"),
+                truncated_title,
+                assignee(work_item)
+            );
+        }
+    }
+}
+
+fn display_capacity(
+    members: &[MemberCapacity],
+    work_items: &[WorkItem],
+    from: Option<NaiveDate>,
+    finish: Option<NaiveDate>,
+    team_days_off: &[(NaiveDate, NaiveDate)],
+) {
+    let mut remaining_by_person: BTreeMap<String, f64> = BTreeMap::new();
+    for work_item in work_items {
+        *remaining_by_person.entry(assignee(work_item)).or_default() += remaining_work(work_item);
+    }
+
+    println!();
+    println!("{}", "👥 Capacity".bold());
+    println!(
+        "{:<30} {:<15} {:>12} {:>12}  Status",
+        "Person", "Activity", "Remaining", "Capacity"
+    );
+    println!("{}", "-".repeat(96));
+
+    for member in members {
+        let days = match (from, finish) {
+            (Some(from), Some(finish)) if from <= finish => {
+                let mut days_off = team_days_off.to_vec();
+                days_off.extend(member.days_off.iter().copied());
+                working_days(from, finish, &days_off)
+            }
+            _ => 0,
+        };
+        let capacity = member.capacity_per_day * days as f64;
+        let remaining = remaining_by_person.remove(&member.name).unwrap_or(0.0);
+        let status = if remaining > capacity {
+            format!("over by {:.1} h", remaining - capacity)
+                .red()
+                .to_string()
+        } else {
+            "ok".green().to_string()
+        };
+        let activity = if member.activities.is_empty() {
+            "-".to_string()
+        } else {
+            member
+                .activities
+                .iter()
+                .map(|(name, per_day)| format!("{name} ({per_day}h/day)"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        println!(
+            "{:<30} {:<15} {:>10.1} h {:>10.1} h  {}",
+            member.name, activity, remaining, capacity, status
+        );
+    }
+
+    // Remaining work assigned to people without capacity set
+    for (name, remaining) in remaining_by_person {
+        if remaining > 0.0 {
+            println!(
+                "{:<30} {:<15} {:>10.1} h {:>12}  {}",
+                name,
+                "-",
+                remaining,
+                "-",
+                "no capacity set".yellow()
+            );
+        }
+    }
+}
+
+pub async fn show_sprint(project: &str, team: &str, iteration: &str) -> Result<()> {
+    let iteration = resolve_iteration(project, team, iteration).await?;
+    let iteration_id = iteration
+        .id
+        .clone()
+        .ok_or_else(|| anyhow!("Iteration ID is missing"))?;
+
+    let (members, team_days_off) = get_member_capacities(project, team, &iteration_id).await?;
+    let today = Local::now().date_naive();
+    let from = display_sprint_header(team, &iteration, today, &team_days_off);
+    let finish = iteration_dates(&iteration).map(|(_, finish)| finish);
+
+    let ids = get_iteration_work_item_ids(project, team, &iteration_id).await?;
+    if ids.is_empty() {
+        println!("\nNo work items planned in this iteration.");
+        return Ok(());
+    }
+
+    let creds = get_credentials()?;
+    let fields = SPRINT_FIELDS.map(String::from);
+    let work_items = boards::get_work_items_details(
+        &boards::create_client()?,
+        &creds.organization,
+        project,
+        &ids,
+        &fields,
+    )
+    .await;
+
+    display_work_items_by_state(&work_items);
+    display_capacity(&members, &work_items, from, finish, &team_days_off);
+
+    println!("\nTotal: {} work items", work_items.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_working_days_skips_weekends() {
+        // Monday 2026-10-05 to Sunday 2026-10-18 is a two week sprint
+        assert_eq!(working_days(date(2026, 10, 5), date(2026, 10, 18), &[]), 10);
+        assert_eq!(working_days(date(2026, 10, 10), date(2026, 10, 11), &[]), 0);
+    }
+
+    #[test]
+    fn test_working_days_skips_days_off() {
+        let days_off = vec![(date(2026, 10, 6), date(2026, 10, 7))];
+        assert_eq!(
+            working_days(date(2026, 10, 5), date(2026, 10, 9), &days_off),
+            3
+        );
+    }
+
+    #[test]
+    fn test_to_date_keeps_date_only_values() {
+        let midnight_utc = DateTime::parse_from_rfc3339("2026-10-05T00:00:00Z").unwrap();
+        assert_eq!(
+            to_date(Some(midnight_utc.timestamp())),
+            Some(date(2026, 10, 5))
+        );
+
+        let instant = DateTime::parse_from_rfc3339("2026-10-05T22:30:00Z").unwrap();
+        assert_eq!(
+            to_date(Some(instant.timestamp())),
+            Some(instant.with_timezone(&Local).date_naive())
+        );
+    }
+}
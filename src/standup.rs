@@ -0,0 +1,388 @@
+use crate::auth::{get_credentials, get_current_user, CurrentUser};
+use crate::boards::{self, sanitize_wiql_value, ChangedSince};
+use crate::history::{self, field_change};
+use crate::pipelines;
+use crate::pr;
+use crate::projects;
+use crate::query;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::git::models::comment::CommentType;
+use azure_devops_rust_api::git::models::git_pull_request::Status;
+use azure_devops_rust_api::git::models::{GitPullRequest, GitPullRequestCommentThread};
+use azure_devops_rust_api::wit::models::WorkItemUpdate;
+use chrono::{DateTime, Local, NaiveDate};
+use std::collections::BTreeMap;
+
+/// Fields shown for the work items in the report
+const WORK_ITEM_FIELDS: [&str; 2] = ["System.Title", "System.WorkItemType"];
+
+/// The activity of the current user in one project
+#[derive(Debug, Default, PartialEq)]
+pub struct ProjectActivity {
+    pub work_items: Vec<String>,
+    pub state_transitions: Vec<String>,
+    pub pull_requests: Vec<String>,
+    pub pipeline_runs: Vec<String>,
+}
+
+impl ProjectActivity {
+    fn is_empty(&self) -> bool {
+        self.work_items.is_empty()
+            && self.state_transitions.is_empty()
+            && self.pull_requests.is_empty()
+            && self.pipeline_runs.is_empty()
+    }
+}
+
+/// Renders the report as Markdown, leaving out projects without activity
+pub fn render_markdown(
+    user: &str,
+    since: NaiveDate,
+    activity: &BTreeMap<String, ProjectActivity>,
+) -> String {
+    let mut markdown = format!("# Stand-up for {user} since {since}\n");
+    let mut any = false;
+    for (project, activity) in activity.iter().filter(|(_, a)| !a.is_empty()) {
+        any = true;
+        markdown.push_str(&format!("\n## {project}\n"));
+        for (heading, lines) in [
+            ("Work items", &activity.work_items),
+            ("State transitions", &activity.state_transitions),
+            ("Pull requests", &activity.pull_requests),
+            ("Pipeline runs", &activity.pipeline_runs),
+        ] {
+            if lines.is_empty() {
+                continue;
+            }
+            markdown.push_str(&format!("\n### {heading}\n\n"));
+            for line in lines {
+                markdown.push_str(&format!("- {line}\n"));
+            }
+        }
+    }
+    if !any {
+        markdown.push_str("\n_No activity._\n");
+    }
+    markdown
+}
+
+fn update_timestamp(update: &WorkItemUpdate) -> Option<i64> {
+    field_change(update, "System.ChangedDate")
+        .and_then(|(_, new)| new?.as_str())
+        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        .map(|d| d.timestamp())
+}
+
+async fn work_item_activity(
+    project: &str,
+    user: &CurrentUser,
+    since: NaiveDate,
+    since_ts: i64,
+    activity: &mut ProjectActivity,
+) -> Result<()> {
+    // EVER narrows the query down, the updates tell which changes are recent
+    let wiql = format!(
+        "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = '{}' AND [System.ChangedDate] >= '{}' AND EVER [System.ChangedBy] = @Me ORDER BY [System.ChangedDate] DESC",
+        sanitize_wiql_value(project),
+        since
+    );
+    let ids = query::result_ids(&query::execute_query(project, Some(&wiql), None).await?);
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let creds = get_credentials()?;
+    let fields = WORK_ITEM_FIELDS.map(String::from);
+    let work_items = boards::get_work_items_details(
+        &boards::create_client()?,
+        &creds.organization,
+        project,
+        &ids,
+        &fields,
+    )
+    .await;
+    let all_updates = history::get_all_updates(project, &ids).await?;
+
+    for (id, updates) in ids.iter().zip(all_updates) {
+        let mine: Vec<WorkItemUpdate> = updates
+            .into_iter()
+            .filter(|u| u.revised_by.as_ref().and_then(|r| r.id.as_deref()) == Some(&user.id))
+            .filter(|u| update_timestamp(u).is_some_and(|ts| ts >= since_ts))
+            .collect();
+        if mine.is_empty() {
+            continue;
+        }
+
+        let work_item = work_items.iter().find(|wi| wi.id == *id);
+        let field = |name: &str| {
+            work_item
+                .and_then(|wi| wi.fields.get(name))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let title = field("System.Title");
+        activity.work_items.push(format!(
+            "{} #{id}: {title} ({} changes)",
+            field("System.WorkItemType"),
+            mine.len()
+        ));
+
+        let changes = history::state_changes(&mine);
+        if let Some(first) = changes.first() {
+            let mut path = vec![first.from.clone().unwrap_or_else(|| "(new)".to_string())];
+            path.extend(changes.iter().map(|c| c.to.clone()));
+            activity
+                .state_transitions
+                .push(format!("#{id} {title}: {}", path.join(" → ")));
+        }
+    }
+    Ok(())
+}
+
+fn pull_request_line(action: &str, pull_request: &GitPullRequest, detail: &str) -> String {
+    format!(
+        "{action} !{}: {} ({}{detail})",
+        pull_request.pull_request_id,
+        pull_request.title.as_deref().unwrap_or_default(),
+        pull_request.repository.name
+    )
+}
+
+/// When a user last voted on a pull request, from the system comments that record votes
+fn latest_vote_time(threads: &[GitPullRequestCommentThread], user_id: &str) -> Option<i64> {
+    threads
+        .iter()
+        .flat_map(|thread| &thread.comment_thread.comments)
+        .filter(|comment| comment.comment_type == Some(CommentType::System))
+        .filter(|comment| comment.author.as_ref().is_some_and(|a| a.id == user_id))
+        .filter(|comment| {
+            comment
+                .content
+                .as_deref()
+                .is_some_and(|c| c.contains(" voted "))
+        })
+        .filter_map(|comment| comment.published_date)
+        .map(|date| date.unix_timestamp())
+        .max()
+}
+
+async fn get_vote_time(
+    project: &str,
+    pull_request: &GitPullRequest,
+    user_id: &str,
+) -> Result<Option<i64>> {
+    let creds = get_credentials()?;
+    let threads = pr::create_client()?
+        .pull_request_threads_client()
+        .list(
+            &creds.organization,
+            &pull_request.repository.id,
+            pull_request.pull_request_id,
+            project,
+        )
+        .await?
+        .value;
+    Ok(latest_vote_time(&threads, user_id))
+}
+
+async fn pull_request_activity(
+    project: &str,
+    user: &CurrentUser,
+    since_ts: i64,
+    activity: &mut ProjectActivity,
+) -> Result<()> {
+    let closed_recently = |pr: &GitPullRequest| {
+        pr.closed_date
+            .is_some_and(|closed| closed.unix_timestamp() >= since_ts)
+    };
+
+    let created = pr::get_pull_requests_for_user(project, Some(&user.id), None).await?;
+    for pull_request in &created {
+        if pull_request.creation_date.unix_timestamp() >= since_ts {
+            activity
+                .pull_requests
+                .push(pull_request_line("Opened", pull_request, ""));
+        }
+    }
+
+    let reviewing = pr::get_pull_requests_for_user(project, None, Some(&user.id)).await?;
+    for pull_request in &reviewing {
+        if pull_request.created_by.id == user.id {
+            continue;
+        }
+        let vote = pull_request
+            .reviewers
+            .iter()
+            .find(|r| r.identity_ref.id == user.id)
+            .and_then(|r| r.vote)
+            .unwrap_or(0);
+        if vote == 0 {
+            continue;
+        }
+        // Active pull requests only count when the vote itself falls in the period
+        let vote_time = match get_vote_time(project, pull_request, &user.id).await {
+            Ok(vote_time) => vote_time,
+            Err(e) => {
+                eprintln!(
+                    "⚠️  Could not get the votes on pull request !{}: {e}",
+                    pull_request.pull_request_id
+                );
+                None
+            }
+        };
+        let voted_recently = match vote_time {
+            Some(voted) => voted >= since_ts,
+            None => pull_request.status != Status::Active && closed_recently(pull_request),
+        };
+        if voted_recently {
+            let detail = format!(", {}", pr::vote_label(vote));
+            activity
+                .pull_requests
+                .push(pull_request_line("Reviewed", pull_request, &detail));
+        }
+    }
+
+    for pull_request in created.iter().chain(&reviewing) {
+        let closed_by_me = pull_request
+            .closed_by
+            .as_ref()
+            .is_some_and(|c| c.id == user.id);
+        if pull_request.status == Status::Completed && closed_by_me && closed_recently(pull_request)
+        {
+            let line = pull_request_line("Completed", pull_request, "");
+            if !activity.pull_requests.contains(&line) {
+                activity.pull_requests.push(line);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn standup(since: &ChangedSince, projects: &[String]) -> Result<()> {
+    let since = since.date(Local::now().date_naive());
+    let since_time = since
+        .and_hms_opt(0, 0, 0)
+        .and_then(|t| t.and_local_timezone(Local).single())
+        .ok_or_else(|| anyhow!("Invalid date {}", since))?;
+    let since_ts = since_time.timestamp();
+
+    let user = get_current_user().await?;
+    let projects = if projects.is_empty() {
+        projects::get_project_names().await?
+    } else {
+        projects.to_vec()
+    };
+
+    // Progress goes to stderr, so the Markdown can be redirected to a file
+    let mut report = BTreeMap::new();
+    for project in &projects {
+        eprintln!("Collecting activity in {project}...");
+        let mut activity = ProjectActivity::default();
+        if let Err(e) = work_item_activity(project, &user, since, since_ts, &mut activity).await {
+            eprintln!("⚠️  Skipping work items in {project}: {e}");
+        }
+        if let Err(e) = pull_request_activity(project, &user, since_ts, &mut activity).await {
+            eprintln!("⚠️  Skipping pull requests in {project}: {e}");
+        }
+        match pipelines::get_runs_requested_for(project, &user.id, since_ts).await {
+            Ok(runs) => {
+                activity.pipeline_runs = runs
+                    .iter()
+                    .map(|run| {
+                        format!(
+                            "{} #{} on {}: {}",
+                            run.pipeline, run.number, run.branch, run.status
+                        )
+                    })
+                    .collect()
+            }
+            Err(e) => eprintln!("⚠️  Skipping pipeline runs in {project}: {e}"),
+        }
+        report.insert(project.clone(), activity);
+    }
+
+    println!("{}", render_markdown(&user.display_name, since, &report));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_render_markdown_groups_by_project() {
+        let mut report = BTreeMap::new();
+        report.insert(
+            "Shop".to_string(),
+            ProjectActivity {
+                work_items: vec!["Bug #12: Checkout fails (2 changes)".to_string()],
+                state_transitions: vec!["#12 Checkout fails: Active → Resolved".to_string()],
+                ..Default::default()
+            },
+        );
+        report.insert("Empty".to_string(), ProjectActivity::default());
+
+        let markdown = render_markdown("Jane Doe", date(2026, 10, 16), &report);
+        assert_eq!(
+            markdown,
+            "# Stand-up for Jane Doe since 2026-10-16\n\
+             \n## Shop\n\
+             \n### Work items\n\n- Bug #12: Checkout fails (2 changes)\n\
+             \n### State transitions\n\n- #12 Checkout fails: Active → Resolved\n"
+        );
+
+        let quiet = render_markdown("Jane Doe", date(2026, 10, 16), &BTreeMap::new());
+        assert!(quiet.ends_with("_No activity._\n"));
+    }
+
+    #[test]
+    fn test_latest_vote_time_reads_system_vote_comments() {
+        let threads: Vec<GitPullRequestCommentThread> = serde_json::from_value(serde_json::json!([
+            {
+                "comments": [
+                    {
+                        "author": { "id": "me", "displayName": "Me" },
+                        "commentType": "system",
+                        "content": "Me voted 10",
+                        "publishedDate": "2024-03-01T10:00:00Z"
+                    },
+                    {
+                        "author": { "id": "me", "displayName": "Me" },
+                        "commentType": "system",
+                        "content": "Me voted 5",
+                        "publishedDate": "2024-03-04T10:00:00Z"
+                    },
+                    {
+                        "author": { "id": "me", "displayName": "Me" },
+                        "commentType": "text",
+                        "content": "I voted for this earlier",
+                        "publishedDate": "2024-03-09T10:00:00Z"
+                    }
+                ]
+            },
+            {
+                "comments": [
+                    {
+                        "author": { "id": "other", "displayName": "Other" },
+                        "commentType": "system",
+                        "content": "Other voted -10",
+                        "publishedDate": "2024-03-08T10:00:00Z"
+                    }
+                ]
+            }
+        ]))
+        .unwrap();
+
+        let expected = chrono::DateTime::parse_from_rfc3339("2024-03-04T10:00:00Z")
+            .unwrap()
+            .timestamp();
+        assert_eq!(latest_vote_time(&threads, "me"), Some(expected));
+        assert_eq!(latest_vote_time(&threads, "someone"), None);
+        assert_eq!(latest_vote_time(&[], "me"), None);
+    }
+}
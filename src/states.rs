@@ -0,0 +1,199 @@
+use crate::auth::get_credentials;
+use crate::boards::create_client;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItemType;
+use std::collections::HashMap;
+
+/// The states of a work item type and the transitions allowed between them
+#[derive(Debug)]
+pub struct TypeStates {
+    pub work_item_type: String,
+    /// State names with their category, e.g. ("Done", "Completed")
+    pub states: Vec<(String, String)>,
+    /// Allowed next states for each state
+    pub transitions: HashMap<String, Vec<String>>,
+}
+
+impl TypeStates {
+    pub fn from_work_item_type(work_item_type: &WorkItemType) -> Self {
+        let states = work_item_type
+            .states
+            .iter()
+            .filter_map(|state| {
+                Some((
+                    state.name.clone()?,
+                    state.category.clone().unwrap_or_default(),
+                ))
+            })
+            .collect();
+
+        let transitions = work_item_type
+            .transitions
+            .as_ref()
+            .and_then(|t| t.as_object())
+            .map(|transitions| {
+                transitions
+                    .iter()
+                    .map(|(from, to)| {
+                        let next = to
+                            .as_array()
+                            .map(|to| {
+                                to.iter()
+                                    .filter_map(|t| t.get("to").and_then(|v| v.as_str()))
+                                    .filter(|t| *t != from)
+                                    .map(|t| t.to_string())
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        (from.clone(), next)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        TypeStates {
+            work_item_type: work_item_type.name.clone().unwrap_or_default(),
+            states,
+            transitions,
+        }
+    }
+
+    fn state_in_category(&self, category: &str) -> Option<&str> {
+        self.states
+            .iter()
+            .find(|(_, c)| c.eq_ignore_ascii_case(category))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// The state used for soft deletes: the Removed-category state, or the
+    /// Completed-category state for types without one
+    pub fn removed_state(&self) -> Option<&str> {
+        self.state_in_category("Removed")
+            .or_else(|| self.state_in_category("Completed"))
+    }
+
+    /// The states a work item can move to from `from`
+    pub fn next_states(&self, from: &str) -> Vec<String> {
+        match self
+            .transitions
+            .iter()
+            .find(|(state, _)| state.eq_ignore_ascii_case(from))
+        {
+            Some((_, next)) => next.clone(),
+            None => self
+                .states
+                .iter()
+                .map(|(name, _)| name.clone())
+                .filter(|name| !name.eq_ignore_ascii_case(from))
+                .collect(),
+        }
+    }
+
+    /// Checks that a work item in state `from` may move to `to`, returning the
+    /// state name with the casing used by the process
+    pub fn validate_transition(&self, from: &str, to: &str) -> Result<String> {
+        let next = self.next_states(from);
+        let state = self
+            .states
+            .iter()
+            .map(|(name, _)| name)
+            .find(|name| name.eq_ignore_ascii_case(to.trim()));
+
+        match state {
+            Some(state) if state.eq_ignore_ascii_case(from) => Ok(state.clone()),
+            Some(state) if next.iter().any(|n| n == state) => Ok(state.clone()),
+            _ => {
+                let reason = if state.is_some() {
+                    format!("cannot move from '{from}' to '{to}'")
+                } else {
+                    format!("'{to}' is not a state of {}", self.work_item_type)
+                };
+                Err(anyhow!(
+                    "Invalid state: {}. Valid next states from '{}': {}",
+                    reason,
+                    from,
+                    next.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+/// Looks up the states and transitions of a work item type
+pub async fn get_type_states(project: &str, work_item_type: &str) -> Result<TypeStates> {
+    let creds = get_credentials()?;
+    let work_item_type = create_client()?
+        .work_item_types_client()
+        .get(creds.organization, project, work_item_type)
+        .await?;
+    Ok(TypeStates::from_work_item_type(&work_item_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn bug_states() -> TypeStates {
+        let work_item_type: WorkItemType = serde_json::from_value(json!({
+            "url": "",
+            "name": "Bug",
+            "states": [
+                { "name": "New", "category": "Proposed" },
+                { "name": "Approved", "category": "Proposed" },
+                { "name": "Committed", "category": "InProgress" },
+                { "name": "Done", "category": "Completed" },
+                { "name": "Removed", "category": "Removed" }
+            ],
+            "transitions": {
+                "": [{ "to": "New" }],
+                "New": [{ "to": "Approved" }, { "to": "Removed" }],
+                "Approved": [{ "to": "Committed" }, { "to": "New" }, { "to": "Removed" }],
+                "Committed": [{ "to": "Done" }, { "to": "Approved" }],
+                "Done": [{ "to": "Committed" }],
+                "Removed": [{ "to": "New" }]
+            }
+        }))
+        .unwrap();
+        TypeStates::from_work_item_type(&work_item_type)
+    }
+
+    #[test]
+    fn test_removed_state_uses_category() {
+        let states = bug_states();
+        assert_eq!(states.removed_state(), Some("Removed"));
+
+        let without_removed = TypeStates {
+            states: vec![
+                ("To Do".to_string(), "Proposed".to_string()),
+                ("Closed".to_string(), "Completed".to_string()),
+            ],
+            ..states
+        };
+        assert_eq!(without_removed.removed_state(), Some("Closed"));
+    }
+
+    #[test]
+    fn test_validate_transition() {
+        let states = bug_states();
+        assert_eq!(
+            states.validate_transition("New", "approved").unwrap(),
+            "Approved"
+        );
+        assert_eq!(states.validate_transition("New", "New").unwrap(), "New");
+
+        let invalid = states
+            .validate_transition("New", "Done")
+            .unwrap_err()
+            .to_string();
+        assert!(invalid.contains("cannot move from 'New' to 'Done'"));
+        assert!(invalid.contains("Approved, Removed"));
+
+        let unknown = states
+            .validate_transition("Committed", "Resolved")
+            .unwrap_err()
+            .to_string();
+        assert!(unknown.contains("'Resolved' is not a state of Bug"));
+        assert!(unknown.contains("Done, Approved"));
+    }
+}
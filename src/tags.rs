@@ -0,0 +1,412 @@
+use crate::auth::get_credentials;
+use crate::boards::{create_client, get_work_items_details, sanitize_wiql_value};
+use crate::bulk::{self, TAGS_FIELD};
+use crate::project::get_project_or_default;
+use crate::query;
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::json_patch_operation::Op;
+use azure_devops_rust_api::wit::models::{JsonPatchOperation, WorkItem, WorkItemTagDefinition};
+use clap::Subcommand;
+use colored::Colorize;
+use dialoguer::Confirm;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+#[derive(Subcommand, Clone)]
+pub enum TagSubCommands {
+    /// List the tags of a project with the number of work items using each
+    List {
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Rename a tag on every work item using it, merging it into an existing tag if needed
+    Rename {
+        /// Tag to rename
+        #[clap(long)]
+        from: String,
+        /// New name of the tag
+        #[clap(long)]
+        to: String,
+        /// Show the work items that would be updated without changing them
+        #[clap(long)]
+        dry_run: bool,
+        /// Skip confirmation prompt and proceed directly
+        #[clap(short = 'y', long)]
+        yes: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// Remove a tag from every work item and delete it
+    Delete {
+        /// Tag to delete
+        #[clap(short, long)]
+        name: String,
+        /// Skip confirmation prompt and proceed directly
+        #[clap(short = 'y', long)]
+        yes: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+}
+
+pub async fn handle_command(subcommand: &TagSubCommands) -> Result<()> {
+    let result = match subcommand {
+        TagSubCommands::List { project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            list_tags(&project_name).await
+        }
+        TagSubCommands::Rename {
+            from,
+            to,
+            dry_run,
+            yes,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            rename_tag(&project_name, from.trim(), to.trim(), *dry_run, *yes).await
+        }
+        TagSubCommands::Delete { name, yes, project } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            delete_tag(&project_name, name.trim(), *yes).await
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("❌ Failed to manage tags: {e}");
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn tags_of(work_item: &WorkItem) -> Vec<&str> {
+    work_item
+        .fields
+        .get(TAGS_FIELD)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .split(';')
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Counts the work items per tag, treating tags that differ only in case as one
+pub fn count_tags(work_items: &[WorkItem]) -> BTreeMap<String, usize> {
+    let mut counts: BTreeMap<String, (String, usize)> = BTreeMap::new();
+    for work_item in work_items {
+        for tag in tags_of(work_item) {
+            counts
+                .entry(tag.to_lowercase())
+                .or_insert_with(|| (tag.to_string(), 0))
+                .1 += 1;
+        }
+    }
+    counts.into_values().collect()
+}
+
+/// Groups tags that look like duplicates of each other, e.g. 'tech-debt' and 'Tech Debt'
+pub fn duplicate_groups<'a>(tags: impl IntoIterator<Item = &'a str>) -> Vec<Vec<&'a str>> {
+    let mut groups: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for tag in tags {
+        let key: String = tag
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(|c| c.to_lowercase())
+            .collect();
+        groups.entry(key).or_default().push(tag);
+    }
+    groups.into_values().filter(|g| g.len() > 1).collect()
+}
+
+async fn get_tagged_work_items(project: &str, tag: Option<&str>) -> Result<Vec<WorkItem>> {
+    let condition = match tag {
+        Some(tag) => format!("[{TAGS_FIELD}] CONTAINS '{}'", sanitize_wiql_value(tag)),
+        None => format!("[{TAGS_FIELD}] <> ''"),
+    };
+    let wiql = format!(
+        "SELECT [System.Id] FROM WorkItems WHERE [System.TeamProject] = '{}' AND {}",
+        sanitize_wiql_value(project),
+        condition
+    );
+    let ids = query::result_ids(&query::execute_query(project, Some(&wiql), None).await?);
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let creds = get_credentials()?;
+    let fields = vec!["System.Title".to_string(), TAGS_FIELD.to_string()];
+    let work_items = get_work_items_details(
+        &create_client()?,
+        &creds.organization,
+        project,
+        &ids,
+        &fields,
+    )
+    .await;
+
+    // CONTAINS may match on part of a tag, so only exact (case-insensitive) matches are kept
+    Ok(match tag {
+        Some(tag) => work_items
+            .into_iter()
+            .filter(|wi| tags_of(wi).iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            .collect(),
+        None => work_items,
+    })
+}
+
+async fn list_tags(project: &str) -> Result<()> {
+    let creds = get_credentials()?;
+    let definitions = create_client()?
+        .tags_client()
+        .list(&creds.organization, project)
+        .await?
+        .value;
+
+    let mut counts = count_tags(&get_tagged_work_items(project, None).await?);
+    for definition in &definitions {
+        if let Some(name) = &definition.name {
+            if !counts.keys().any(|t| t.eq_ignore_ascii_case(name)) {
+                counts.insert(name.clone(), 0);
+            }
+        }
+    }
+
+    if counts.is_empty() {
+        println!("No tags found in project '{project}'");
+        return Ok(());
+    }
+
+    println!("{:<40} {:>10}", "Tag", "Work Items");
+    println!("{}", "-".repeat(51));
+    let mut sorted: Vec<(&String, &usize)> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    for (tag, count) in sorted {
+        println!("{tag:<40} {count:>10}");
+    }
+    println!("\nTotal: {} tags", counts.len());
+
+    let duplicates = duplicate_groups(counts.keys().map(|t| t.as_str()));
+    if !duplicates.is_empty() {
+        println!();
+        println!("{}", "Possible duplicates:".yellow().bold());
+        for group in duplicates {
+            println!("  {}", group.join(", "));
+        }
+        println!("Merge them with 'azdocli boards tags rename --from <tag> --to <tag>'");
+    }
+    Ok(())
+}
+
+/// Builds the updates that replace `from` with `to` (or drop it) on each work item
+fn retag_updates(
+    work_items: &[WorkItem],
+    from: &str,
+    to: Option<&str>,
+) -> Vec<(i32, Vec<JsonPatchOperation>)> {
+    work_items
+        .iter()
+        .map(|work_item| {
+            let current = tags_of(work_item).join("; ");
+            let without = bulk::apply_tag_changes(&current, &[], &[from]);
+            let tags = match to {
+                Some(to) => bulk::apply_tag_changes(&without, &[to], &[]),
+                None => without,
+            };
+            let patch = vec![JsonPatchOperation {
+                from: None,
+                op: Some(Op::Add),
+                path: Some(format!("/fields/{TAGS_FIELD}")),
+                value: Some(json!(tags)),
+            }];
+            (work_item.id, patch)
+        })
+        .collect()
+}
+
+fn confirm(prompt: String, skip_confirmation: bool) -> Result<bool> {
+    if skip_confirmation {
+        return Ok(true);
+    }
+    Ok(Confirm::new()
+        .with_prompt(prompt)
+        .default(false)
+        .interact()?)
+}
+
+async fn send_retag_updates(updates: Vec<(i32, Vec<JsonPatchOperation>)>) -> Result<()> {
+    let total = updates.len();
+    let failures = bulk::send_updates(updates).await?;
+    println!();
+    if failures > 0 {
+        return Err(anyhow!(
+            "{} of {} work items were not updated",
+            failures,
+            total
+        ));
+    }
+    Ok(())
+}
+
+async fn rename_tag(
+    project: &str,
+    from: &str,
+    to: &str,
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<()> {
+    if from.is_empty() || to.is_empty() || to.contains(';') {
+        return Err(anyhow!("Tags must not be empty or contain ';'"));
+    }
+    if from == to {
+        return Err(anyhow!("The tag is already named '{}'", to));
+    }
+
+    // Tags are case-insensitive, so a change of case renames the tag definition itself
+    if from.eq_ignore_ascii_case(to) {
+        if dry_run {
+            println!("Dry run: tag '{from}' would be renamed to '{to}'");
+            return Ok(());
+        }
+        let creds = get_credentials()?;
+        let mut definition = WorkItemTagDefinition::new();
+        definition.name = Some(to.to_string());
+        create_client()?
+            .tags_client()
+            .update(&creds.organization, definition, project, from)
+            .await?;
+        println!("{}", format!("✅ Renamed tag '{from}' to '{to}'").green());
+        return Ok(());
+    }
+
+    let work_items = get_tagged_work_items(project, Some(from)).await?;
+    if work_items.is_empty() {
+        println!("No work items are tagged '{from}'");
+        return Ok(());
+    }
+
+    println!(
+        "{} work items are tagged '{}':",
+        work_items.len(),
+        from.bold()
+    );
+    for work_item in &work_items {
+        println!(
+            "  #{:<8} {}",
+            work_item.id,
+            work_item
+                .fields
+                .get("System.Title")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+        );
+    }
+    if dry_run {
+        println!("\nDry run: the tag would be replaced by '{to}'");
+        return Ok(());
+    }
+    if !confirm(
+        format!(
+            "Replace tag '{from}' with '{to}' on {} work items?",
+            work_items.len()
+        ),
+        skip_confirmation,
+    )? {
+        println!("Rename cancelled.");
+        return Ok(());
+    }
+
+    send_retag_updates(retag_updates(&work_items, from, Some(to))).await?;
+    println!(
+        "{}",
+        format!(
+            "✅ Renamed tag '{from}' to '{to}' on {} work items",
+            work_items.len()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+async fn delete_tag(project: &str, name: &str, skip_confirmation: bool) -> Result<()> {
+    let work_items = get_tagged_work_items(project, Some(name)).await?;
+    if !confirm(
+        format!(
+            "Remove tag '{name}' from {} work items and delete it?",
+            work_items.len()
+        ),
+        skip_confirmation,
+    )? {
+        println!("Delete operation cancelled.");
+        return Ok(());
+    }
+
+    if !work_items.is_empty() {
+        send_retag_updates(retag_updates(&work_items, name, None)).await?;
+    }
+
+    // Unused tags may already have been cleaned up by Azure DevOps
+    let creds = get_credentials()?;
+    if let Err(e) = create_client()?
+        .tags_client()
+        .delete(&creds.organization, project, name)
+        .await
+    {
+        let not_found = e.http_status().map(u16::from) == Some(404);
+        if !not_found {
+            eprintln!("❌ Failed to delete tag '{name}': {e}");
+            return Err(anyhow!("Failed to delete tag '{}': {}", name, e));
+        }
+        if work_items.is_empty() {
+            return Err(anyhow!("Tag '{}' not found: {}", name, e));
+        }
+        println!(
+            "{}",
+            format!(
+                "✅ Removed tag '{name}' from {} work items",
+                work_items.len()
+            )
+            .green()
+        );
+        println!("⚠️  Tag '{name}' was already deleted by Azure DevOps");
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "✅ Deleted tag '{name}' and removed it from {} work items",
+            work_items.len()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_tags_ignores_case() {
+        let work_items: Vec<WorkItem> = serde_json::from_value(json!([
+            { "url": "", "id": 1, "rev": 1, "fields": { "System.Tags": "ui; Tech Debt" } },
+            { "url": "", "id": 2, "rev": 1, "fields": { "System.Tags": "UI" } },
+            { "url": "", "id": 3, "rev": 1, "fields": {} }
+        ]))
+        .unwrap();
+
+        let counts = count_tags(&work_items);
+        assert_eq!(counts.get("ui"), Some(&2));
+        assert_eq!(counts.get("Tech Debt"), Some(&1));
+        assert_eq!(counts.len(), 2);
+    }
+
+    #[test]
+    fn test_duplicate_groups() {
+        let groups = duplicate_groups(["tech-debt", "Tech Debt", "ui", "techdebt", "backend"]);
+        assert_eq!(groups, vec![vec!["tech-debt", "Tech Debt", "techdebt"]]);
+    }
+}
@@ -0,0 +1,404 @@
+use crate::auth::get_credentials;
+use crate::boards;
+use crate::config::get_config_dir;
+use crate::project::get_project_or_default;
+use crate::query::format_field_value;
+use anyhow::{anyhow, Result};
+use chrono::Local;
+use clap::Subcommand;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// Editable fields that are still not copied into templates: the title and type are
+/// given on the command line, and a new work item starts in its initial state
+const NOT_COPIED_FIELDS: [&str; 4] = [
+    "System.Title",
+    "System.WorkItemType",
+    "System.State",
+    "System.Reason",
+];
+
+#[derive(Subcommand, Clone)]
+pub enum TemplateSubCommands {
+    /// Save the fields of an existing work item as a template
+    Save {
+        /// ID of the work item to copy the fields from
+        #[clap(long)]
+        from_id: String,
+        /// Name of the template, e.g. 'bug-prod'
+        #[clap(short, long)]
+        name: String,
+        /// Replace a value with a placeholder, e.g. 'service=payments' turns 'payments' into '{{service}}'
+        #[clap(long = "var")]
+        vars: Vec<String>,
+        /// Overwrite an existing template with the same name
+        #[clap(long)]
+        force: bool,
+        /// Team project name (optional if default project is set)
+        #[clap(short, long)]
+        project: Option<String>,
+    },
+    /// List the saved templates
+    List,
+    /// Show the fields of a template
+    Show {
+        /// Name of the template
+        #[clap(short, long)]
+        name: String,
+    },
+    /// Delete a template
+    Delete {
+        /// Name of the template
+        #[clap(short, long)]
+        name: String,
+    },
+}
+
+/// A reusable set of work item fields, stored as JSON in the config directory
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct WorkItemTemplate {
+    pub work_item_type: String,
+    pub fields: BTreeMap<String, Value>,
+}
+
+pub async fn handle_command(subcommand: &TemplateSubCommands) -> Result<()> {
+    match subcommand {
+        TemplateSubCommands::Save {
+            from_id,
+            name,
+            vars,
+            force,
+            project,
+        } => {
+            let project_name = get_project_or_default(project.as_deref())?;
+            save_template(&project_name, from_id, name, vars, *force).await?;
+        }
+        TemplateSubCommands::List => list_templates()?,
+        TemplateSubCommands::Show { name } => {
+            let template = load_template(name)?;
+            println!("📋 Template: {}", name.bold());
+            println!("📌 Type: {}", template.work_item_type);
+            for (field, value) in &template.fields {
+                println!("  {field}: {}", format_field_value(value));
+            }
+        }
+        TemplateSubCommands::Delete { name } => {
+            let path = template_path(name)?;
+            if !path.exists() {
+                return Err(anyhow!("Template '{}' not found", name));
+            }
+            fs::remove_file(path)?;
+            println!("{}", format!("✅ Template '{name}' deleted").green());
+        }
+    }
+    Ok(())
+}
+
+async fn save_template(
+    project: &str,
+    from_id: &str,
+    name: &str,
+    vars: &[String],
+    force: bool,
+) -> Result<()> {
+    let path = template_path(name)?;
+    if path.exists() && !force {
+        return Err(anyhow!(
+            "Template '{}' already exists, use --force to overwrite it",
+            name
+        ));
+    }
+
+    let work_item = boards::get_work_item(project, from_id, None).await?;
+    let work_item_type = work_item
+        .fields
+        .get("System.WorkItemType")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    let editable = editable_fields(project, work_item_type).await?;
+    let template = template_from_fields(&work_item.fields, &parse_vars(vars)?, &editable);
+    fs::write(&path, serde_json::to_string_pretty(&template)?)?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Saved template '{}' with {} fields",
+            name,
+            template.fields.len()
+        )
+        .green()
+    );
+    println!("Template file: {}", path.display());
+    Ok(())
+}
+
+fn templates_dir() -> Result<PathBuf> {
+    let dir = get_config_dir()?.join("templates");
+    if !dir.exists() {
+        fs::create_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
+fn template_path(name: &str) -> Result<PathBuf> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(anyhow!(
+            "Invalid template name '{}', use letters, digits, '-' and '_'",
+            name
+        ));
+    }
+    Ok(templates_dir()?.join(format!("{name}.json")))
+}
+
+pub fn load_template(name: &str) -> Result<WorkItemTemplate> {
+    let path = template_path(name)?;
+    if !path.exists() {
+        return Err(anyhow!("Template '{}' not found", name));
+    }
+    let content = fs::read_to_string(&path)?;
+    serde_json::from_str(&content)
+        .map_err(|e| anyhow!("Invalid template file {}: {}", path.display(), e))
+}
+
+fn list_templates() -> Result<()> {
+    let mut names: Vec<String> = fs::read_dir(templates_dir()?)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "json"))
+        .filter_map(|path| path.file_stem()?.to_str().map(|s| s.to_string()))
+        .collect();
+    names.sort();
+
+    if names.is_empty() {
+        println!("No templates saved yet.");
+        return Ok(());
+    }
+
+    println!("{:<30} {:<20}", "Name", "Type");
+    println!("{}", "-".repeat(50));
+    for name in &names {
+        let work_item_type = load_template(name)
+            .map(|t| t.work_item_type)
+            .unwrap_or_else(|_| "(invalid)".to_string());
+        println!("{name:<30} {work_item_type:<20}");
+    }
+    Ok(())
+}
+
+/// Parses `name=value` variables
+pub fn parse_vars(vars: &[String]) -> Result<Vec<(String, String)>> {
+    vars.iter()
+        .map(|var| {
+            var.split_once('=')
+                .map(|(name, value)| (name.trim().to_string(), value.to_string()))
+                .filter(|(name, _)| !name.is_empty())
+                .ok_or_else(|| anyhow!("Invalid variable '{}', expected 'name=value'", var))
+        })
+        .collect()
+}
+
+/// Reference names of the fields of a work item type that are not read-only
+async fn editable_fields(project: &str, work_item_type: &str) -> Result<HashSet<String>> {
+    let creds = get_credentials()?;
+    let client = boards::create_client()?;
+    let read_only: HashSet<String> = client
+        .fields_client()
+        .list(&creds.organization, project)
+        .await
+        .map_err(|e| anyhow!("Failed to get field definitions: {}", e))?
+        .value
+        .into_iter()
+        .filter(|field| field.work_item_field.read_only == Some(true))
+        .filter_map(|field| field.work_item_field.reference_name)
+        .collect();
+
+    Ok(client
+        .work_item_types_field_client()
+        .list(&creds.organization, project, work_item_type)
+        .await
+        .map_err(|e| anyhow!("Failed to get the fields of '{}': {}", work_item_type, e))?
+        .value
+        .into_iter()
+        .filter_map(|field| {
+            field
+                .work_item_type_field_instance_base
+                .work_item_field_reference
+                .reference_name
+        })
+        .filter(|field| !read_only.contains(field))
+        .collect())
+}
+
+fn is_copied_field(field: &str, editable: &HashSet<String>) -> bool {
+    editable.contains(field) && !NOT_COPIED_FIELDS.contains(&field)
+}
+
+/// Builds a template from a work item's fields, turning the given values into placeholders.
+///
+/// Only the `editable` fields of the type are copied, so the template can be used to create
+/// a work item.
+pub fn template_from_fields(
+    fields: &Value,
+    vars: &[(String, String)],
+    editable: &HashSet<String>,
+) -> WorkItemTemplate {
+    let work_item_type = fields
+        .get("System.WorkItemType")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let fields = fields
+        .as_object()
+        .map(|fields| {
+            fields
+                .iter()
+                .filter(|(field, _)| is_copied_field(field, editable))
+                .map(|(field, value)| {
+                    // Identities are stored by their unique name, which is what updates accept
+                    let value = match value.get("uniqueName").and_then(|v| v.as_str()) {
+                        Some(unique_name) => Value::String(unique_name.to_string()),
+                        None => value.clone(),
+                    };
+                    let value = match value {
+                        Value::String(mut text) => {
+                            for (name, literal) in vars {
+                                if !literal.is_empty() {
+                                    text = text.replace(literal, &format!("{{{{{name}}}}}"));
+                                }
+                            }
+                            Value::String(text)
+                        }
+                        other => other,
+                    };
+                    (field.clone(), value)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    WorkItemTemplate {
+        work_item_type,
+        fields,
+    }
+}
+
+/// Replaces `{{name}}` placeholders; `{{date}}` defaults to today's date
+pub fn render(text: &str, vars: &[(String, String)]) -> Result<String> {
+    let today = Local::now().date_naive().to_string();
+    let mut output = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + end].trim();
+        let value = vars
+            .iter()
+            .find(|(var, _)| var == name)
+            .map(|(_, value)| value.as_str())
+            .or((name == "date").then_some(today.as_str()))
+            .ok_or_else(|| {
+                anyhow!(
+                    "Missing value for placeholder '{{{{{}}}}}', pass --var {}=...",
+                    name,
+                    name
+                )
+            })?;
+        output.push_str(&rest[..start]);
+        output.push_str(value);
+        rest = &rest[start + end + 2..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Fills in the template's placeholders, returning the fields to create the work item with
+pub fn render_fields(
+    template: &WorkItemTemplate,
+    vars: &[(String, String)],
+) -> Result<Vec<(String, Value)>> {
+    template
+        .fields
+        .iter()
+        .map(|(field, value)| {
+            let value = match value {
+                Value::String(text) => Value::String(render(text, vars)?),
+                other => other.clone(),
+            };
+            Ok((field.clone(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_template_from_fields_copies_editable_fields() {
+        let fields = json!({
+            "System.Id": 12,
+            "System.Title": "Checkout down in payments",
+            "System.WorkItemType": "Bug",
+            "System.AreaPath": "Shop\\payments",
+            "System.AssignedTo": { "displayName": "Jane Doe", "uniqueName": "jane@contoso.com" },
+            "Microsoft.VSTS.Common.Severity": "1 - Critical",
+            "WEF_123_Kanban.Column": "Active"
+        });
+        let vars = vec![("service".to_string(), "payments".to_string())];
+        // Id and the Kanban column are read-only, Title and type come from the command line
+        let editable: HashSet<String> = [
+            "System.Title",
+            "System.WorkItemType",
+            "System.AreaPath",
+            "System.AssignedTo",
+            "Microsoft.VSTS.Common.Severity",
+        ]
+        .iter()
+        .map(|f| f.to_string())
+        .collect();
+        let template = template_from_fields(&fields, &vars, &editable);
+
+        assert_eq!(template.work_item_type, "Bug");
+        assert_eq!(
+            template.fields.keys().collect::<Vec<_>>(),
+            vec![
+                "Microsoft.VSTS.Common.Severity",
+                "System.AreaPath",
+                "System.AssignedTo"
+            ]
+        );
+        assert_eq!(
+            template.fields["System.AreaPath"],
+            json!("Shop\\{{service}}")
+        );
+        assert_eq!(
+            template.fields["System.AssignedTo"],
+            json!("jane@contoso.com")
+        );
+    }
+
+    #[test]
+    fn test_render_replaces_placeholders() {
+        let vars = vec![("service".to_string(), "payments".to_string())];
+        assert_eq!(
+            render("Outage in {{service}} ({{ service }})", &vars).unwrap(),
+            "Outage in payments (payments)"
+        );
+        assert_eq!(render("no placeholders", &[]).unwrap(), "no placeholders");
+
+        let missing = render("{{region}}", &vars).unwrap_err().to_string();
+        assert!(missing.contains("--var region="));
+    }
+}
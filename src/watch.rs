@@ -0,0 +1,299 @@
+use crate::auth::get_credentials;
+use crate::boards::{self, create_client, get_work_items_details};
+use crate::config::get_config_dir;
+use crate::history::{diff_fields, plain_value};
+use crate::query::{self, format_field_value};
+use anyhow::{anyhow, Result};
+use azure_devops_rust_api::wit::models::WorkItem;
+use chrono::Local;
+use colored::Colorize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Followed work item IDs per project, kept locally so following sends no emails
+type FollowedItems = BTreeMap<String, BTreeSet<i32>>;
+
+fn followed_path() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("following.json"))
+}
+
+fn load_followed() -> Result<FollowedItems> {
+    let path = followed_path()?;
+    if !path.exists() {
+        return Ok(FollowedItems::new());
+    }
+    let content = fs::read_to_string(&path)?;
+    serde_json::from_str(&content).map_err(|e| anyhow!("Invalid file {}: {}", path.display(), e))
+}
+
+fn save_followed(followed: &FollowedItems) -> Result<()> {
+    fs::write(followed_path()?, serde_json::to_string_pretty(followed)?)?;
+    Ok(())
+}
+
+fn parse_id(id: &str) -> Result<i32> {
+    id.parse::<i32>()
+        .map_err(|_| anyhow!("Invalid work item ID, must be a number"))
+}
+
+pub async fn follow(project: &str, id: &str) -> Result<()> {
+    let id_int = parse_id(id)?;
+    // Only existing work items can be followed
+    let work_item = boards::get_work_item(project, id, None).await?;
+
+    let mut followed = load_followed()?;
+    if !followed
+        .entry(project.to_string())
+        .or_default()
+        .insert(id_int)
+    {
+        println!("Already following work item {id}");
+        return Ok(());
+    }
+    save_followed(&followed)?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Following work item {id}: {}",
+            work_item
+                .fields
+                .get("System.Title")
+                .map(format_field_value)
+                .unwrap_or_default()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+pub fn unfollow(project: &str, id: &str) -> Result<()> {
+    let id_int = parse_id(id)?;
+    let mut followed = load_followed()?;
+    let removed = followed
+        .get_mut(project)
+        .is_some_and(|ids| ids.remove(&id_int));
+    if !removed {
+        return Err(anyhow!("Work item {} is not followed", id));
+    }
+    followed.retain(|_, ids| !ids.is_empty());
+    save_followed(&followed)?;
+
+    println!("{}", format!("✅ Stopped following work item {id}").green());
+    Ok(())
+}
+
+/// Describes the fields that differ between two versions of a work item,
+/// e.g. "State: Active → Resolved"
+pub fn field_changes(old: &Value, new: &Value) -> Vec<String> {
+    diff_fields(old, new)
+        .iter()
+        .map(|diff| {
+            format!(
+                "{}: {} → {}",
+                diff.field.rsplit('.').next().unwrap_or(&diff.field),
+                plain_value(diff.old.as_ref()),
+                plain_value(diff.new.as_ref())
+            )
+        })
+        .collect()
+}
+
+async fn watched_ids(project: &str, wiql: Option<&str>) -> Result<Vec<i32>> {
+    match wiql {
+        Some(wiql) => Ok(query::result_ids(
+            &query::execute_query(project, Some(wiql), None).await?,
+        )),
+        None => Ok(load_followed()?
+            .get(project)
+            .map(|ids| ids.iter().copied().collect())
+            .unwrap_or_default()),
+    }
+}
+
+async fn fetch(project: &str, ids: &[i32]) -> Result<Vec<WorkItem>> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let creds = get_credentials()?;
+    Ok(get_work_items_details(&create_client()?, &creds.organization, project, ids, &[]).await)
+}
+
+/// Runs the notification hook with the change in environment variables
+async fn notify(command: &str, work_item: &WorkItem, message: &str) {
+    #[cfg(target_os = "windows")]
+    let mut hook = tokio::process::Command::new("cmd");
+    #[cfg(target_os = "windows")]
+    hook.arg("/C").arg(command);
+
+    #[cfg(not(target_os = "windows"))]
+    let mut hook = tokio::process::Command::new("sh");
+    #[cfg(not(target_os = "windows"))]
+    hook.arg("-c").arg(command);
+
+    let title = work_item
+        .fields
+        .get("System.Title")
+        .map(format_field_value)
+        .unwrap_or_default();
+    let result = hook
+        .env("AZDOCLI_WORK_ITEM_ID", work_item.id.to_string())
+        .env("AZDOCLI_TITLE", title)
+        .env("AZDOCLI_MESSAGE", message)
+        .status()
+        .await;
+    match result {
+        Ok(status) if !status.success() => {
+            eprintln!("⚠️  Notification command exited with {status}")
+        }
+        Err(e) => eprintln!("⚠️  Failed to run notification command: {e}"),
+        _ => {}
+    }
+}
+
+fn report_changes(previous: &WorkItem, current: &WorkItem) -> Vec<String> {
+    let title = current
+        .fields
+        .get("System.Title")
+        .map(format_field_value)
+        .unwrap_or_default();
+    let changed_by = current
+        .fields
+        .get("System.ChangedBy")
+        .map(format_field_value)
+        .unwrap_or_default();
+
+    field_changes(&previous.fields, &current.fields)
+        .into_iter()
+        .map(|change| {
+            let time = Local::now().format("%H:%M:%S");
+            println!(
+                "{} {} {}: {} {}",
+                time.to_string().dimmed(),
+                format!("#{}", current.id).cyan().bold(),
+                title,
+                change,
+                format!("(by {changed_by})").dimmed()
+            );
+            format!("#{} {}: {}", current.id, title, change)
+        })
+        .collect()
+}
+
+pub async fn watch(
+    project: &str,
+    wiql: Option<&str>,
+    interval: u64,
+    notify_command: Option<&str>,
+) -> Result<()> {
+    if interval == 0 {
+        return Err(anyhow!("The interval must be at least one second"));
+    }
+
+    let ids = watched_ids(project, wiql).await?;
+    if ids.is_empty() && wiql.is_none() {
+        return Err(anyhow!(
+            "No work items followed in project '{}', follow one with 'azdocli boards work-item follow --id <ID>'",
+            project
+        ));
+    }
+
+    let mut known: HashMap<i32, WorkItem> = fetch(project, &ids)
+        .await?
+        .into_iter()
+        .map(|wi| (wi.id, wi))
+        .collect();
+    println!(
+        "👀 Watching {} work items every {}s, press Ctrl+C to stop",
+        known.len(),
+        interval
+    );
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {
+                println!("\nStopped watching.");
+                return Ok(());
+            }
+            _ = tokio::time::sleep(Duration::from_secs(interval)) => {}
+        }
+
+        // A failed poll is reported and retried on the next interval
+        let ids = match watched_ids(project, wiql).await {
+            Ok(ids) => ids,
+            Err(e) => {
+                eprintln!("⚠️  Failed to refresh the watched work items: {e}");
+                continue;
+            }
+        };
+        let work_items = match fetch(project, &ids).await {
+            Ok(work_items) => work_items,
+            Err(e) => {
+                eprintln!("⚠️  Failed to refresh the watched work items: {e}");
+                continue;
+            }
+        };
+
+        for work_item in work_items {
+            let messages = match known.get(&work_item.id) {
+                Some(previous) if previous.rev != work_item.rev => {
+                    report_changes(previous, &work_item)
+                }
+                Some(_) => Vec::new(),
+                None => {
+                    let message = format!(
+                        "#{} {} now matches the query",
+                        work_item.id,
+                        work_item
+                            .fields
+                            .get("System.Title")
+                            .map(format_field_value)
+                            .unwrap_or_default()
+                    );
+                    println!(
+                        "{} {}",
+                        Local::now().format("%H:%M:%S").to_string().dimmed(),
+                        message
+                    );
+                    vec![message]
+                }
+            };
+            if let (Some(command), false) = (notify_command, messages.is_empty()) {
+                notify(command, &work_item, &messages.join("\n")).await;
+            }
+            known.insert(work_item.id, work_item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_changes() {
+        let old = json!({
+            "System.Rev": 3,
+            "System.State": "Active",
+            "System.AssignedTo": { "displayName": "Jane Doe" },
+            "System.Title": "Checkout fails"
+        });
+        let new = json!({
+            "System.Rev": 4,
+            "System.State": "Resolved",
+            "System.AssignedTo": { "displayName": "John Smith" },
+            "System.Title": "Checkout fails"
+        });
+        assert_eq!(
+            field_changes(&old, &new),
+            vec![
+                "AssignedTo: Jane Doe → John Smith",
+                "State: Active → Resolved"
+            ]
+        );
+    }
+}
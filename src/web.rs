@@ -0,0 +1,126 @@
+use crate::auth::get_credentials;
+use crate::config::get_server_url;
+use anyhow::{anyhow, Result};
+use reqwest::Url;
+use std::process::Command;
+
+/// Server used when no `server_url` is configured
+const DEFAULT_SERVER_URL: &str = "https://dev.azure.com";
+
+/// Builds a web URL from the server, the organization and path segments,
+/// encoding each segment so names with spaces or special characters work
+fn build_web_url(server: &str, organization: &str, segments: &[&str]) -> Result<Url> {
+    let mut url = Url::parse(server.trim_end_matches('/'))
+        .map_err(|e| anyhow!("Invalid server URL '{}': {}", server, e))?;
+    url.path_segments_mut()
+        .map_err(|_| anyhow!("Invalid server URL '{}'", server))?
+        .pop_if_empty()
+        .push(organization)
+        .extend(segments);
+    Ok(url)
+}
+
+/// Builds a web URL for the logged in organization on the configured server
+pub fn web_url(segments: &[&str]) -> Result<Url> {
+    let creds = get_credentials()?;
+    let server = get_server_url().unwrap_or_else(|| DEFAULT_SERVER_URL.to_string());
+    build_web_url(&server, &creds.organization, segments)
+}
+
+/// Builds a REST API URL (`.../_apis/...`) from the server, the organization,
+/// an optional project and the API path segments
+fn build_api_url(
+    server: &str,
+    organization: &str,
+    project: Option<&str>,
+    segments: &[&str],
+) -> Result<Url> {
+    let mut path: Vec<&str> = project.into_iter().collect();
+    path.push("_apis");
+    path.extend_from_slice(segments);
+    build_web_url(server, organization, &path)
+}
+
+/// Builds a REST API URL for the logged in organization on the configured server,
+/// scoped to `project` when one is given
+pub fn api_url(project: Option<&str>, segments: &[&str]) -> Result<Url> {
+    let creds = get_credentials()?;
+    let server = get_server_url().unwrap_or_else(|| DEFAULT_SERVER_URL.to_string());
+    build_api_url(&server, &creds.organization, project, segments)
+}
+
+/// Opens a URL in the default web browser
+pub fn open_in_browser(url: &str) -> Result<()> {
+    println!("Opening in browser: {url}");
+
+    #[cfg(target_os = "windows")]
+    Command::new("explorer").arg(url).spawn()?;
+
+    #[cfg(target_os = "macos")]
+    Command::new("open").arg(url).spawn()?;
+
+    #[cfg(target_os = "linux")]
+    Command::new("xdg-open").arg(url).spawn()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_web_url_encodes_segments() {
+        let url = build_web_url(
+            "https://dev.azure.com",
+            "contoso",
+            &["My Project", "_workitems", "edit", "42"],
+        )
+        .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://dev.azure.com/contoso/My%20Project/_workitems/edit/42"
+        );
+    }
+
+    #[test]
+    fn test_build_web_url_with_server_collection() {
+        let url = build_web_url(
+            "https://tfs.contoso.com/tfs/",
+            "DefaultCollection",
+            &["Shop", "_git", "web"],
+        )
+        .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://tfs.contoso.com/tfs/DefaultCollection/Shop/_git/web"
+        );
+    }
+
+    #[test]
+    fn test_build_api_url() {
+        let url = build_api_url(
+            "https://tfs.contoso.com/tfs",
+            "DefaultCollection",
+            Some("My Project"),
+            &["wit", "attachments"],
+        )
+        .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://tfs.contoso.com/tfs/DefaultCollection/My%20Project/_apis/wit/attachments"
+        );
+
+        let url = build_api_url(
+            "https://dev.azure.com",
+            "contoso",
+            None,
+            &["connectionData"],
+        )
+        .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://dev.azure.com/contoso/_apis/connectionData"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::auth::get_credentials;
 use crate::project::get_project_or_default;
+use crate::web;
 use anyhow::{anyhow, Result};
 use azure_devops_rust_api::search::{self, models as search_models};
 use azure_devops_rust_api::wiki::{self, models};
@@ -22,6 +23,9 @@ pub enum WikiSubCommands {
         /// Team project name (optional if default project is set)
         #[clap(short, long)]
         project: Option<String>,
+        /// Open in web browser
+        #[clap(long)]
+        web: bool,
     },
     /// Manage wiki pages
     Page {
@@ -113,10 +117,20 @@ pub async fn handle_command(subcommand: &WikiSubCommands) -> Result<()> {
             let wikis = list_wikis(&project_name).await?;
             display_wikis(&wikis);
         }
-        WikiSubCommands::Show { id, project } => {
+        WikiSubCommands::Show { id, project, web } => {
             let project_name = get_project_or_default(project.as_deref())?;
             let wiki = resolve_wiki(&project_name, id.as_deref()).await?;
-            display_wiki_details(&wiki);
+            if *web {
+                let name = wiki
+                    .wiki_create_base_parameters
+                    .name
+                    .as_deref()
+                    .unwrap_or_default();
+                let url = web::web_url(&[&project_name, "_wiki", "wikis", name])?;
+                web::open_in_browser(url.as_str())?;
+            } else {
+                display_wiki_details(&wiki);
+            }
         }
         WikiSubCommands::Page { subcommand } => {
             handle_page_command(subcommand).await?;
@@ -284,17 +298,7 @@ async fn handle_page_command(subcommand: &WikiPageSubCommands) -> Result<()> {
             let page = get_page(&project_name, &wiki_id, path).await?;
             if *web {
                 if let Some(ref remote_url) = page.remote_url {
-                    println!("Opening {} in browser...", remote_url);
-                    #[cfg(target_os = "macos")]
-                    std::process::Command::new("open").arg(remote_url).spawn()?;
-                    #[cfg(target_os = "linux")]
-                    std::process::Command::new("xdg-open")
-                        .arg(remote_url)
-                        .spawn()?;
-                    #[cfg(target_os = "windows")]
-                    std::process::Command::new("explorer")
-                        .arg(remote_url)
-                        .spawn()?;
+                    web::open_in_browser(remote_url)?;
                 } else {
                     println!("Web URL not available for this page.");
                 }